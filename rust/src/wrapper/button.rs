@@ -0,0 +1,126 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::object_movement::ObjectMovement;
+
+/// The `Button` struct represents a worksheet button object.
+///
+/// The `Button` struct is used to create an Excel "Form Control" button object
+/// to represent a button on a worksheet.
+///
+/// <img src="https://rustxlsxwriter.github.io/images/doc_button_intro.png">
+///
+/// The worksheet button object is mainly provided as a way to trigger a VBA
+/// macro. It is used in conjunction with the {@link Worksheet#insertButton}
+/// method.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct Button {
+    pub(crate) inner: Arc<Mutex<xlsx::Button>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::Button::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        return Button {
+            inner: Arc::clone(&$self.inner),
+        }
+    };
+}
+
+#[wasm_bindgen]
+impl Button {
+    /// Create a new Button object to represent an Excel Form Control button.
+    ///
+    /// @returns {Button} - The Button object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> Button {
+        Button {
+            inner: Arc::new(Mutex::new(xlsx::Button::new())),
+        }
+    }
+
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, xlsx::Button> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Set the button caption.
+    ///
+    /// The default button caption in Excel is "Button 1", "Button 2" etc. This
+    /// method can be used to change that caption to some other text.
+    ///
+    /// @param {string} caption - The text to display on the button. It must be
+    ///   less than or equal to 255 characters.
+    /// @returns {Button} - The Button object.
+    #[wasm_bindgen(js_name = "setCaption", skip_jsdoc)]
+    pub fn set_caption(&self, caption: &str) -> Button {
+        impl_method!(self.set_caption(caption));
+    }
+
+    /// Set the macro associated with the button.
+    ///
+    /// The `setMacro()` method can be used to associate an existing VBA macro
+    /// with a button object.
+    ///
+    /// @param {string} name - The macro name. It should be the same as it
+    ///   appears in the Excel macros dialog.
+    /// @returns {Button} - The Button object.
+    #[wasm_bindgen(js_name = "setMacro", skip_jsdoc)]
+    pub fn set_macro(&self, name: &str) -> Button {
+        impl_method!(self.set_macro(name));
+    }
+
+    /// Set the width of the button in pixels.
+    ///
+    /// @param {number} width - The button width in pixels.
+    /// @returns {Button} - The Button object.
+    #[wasm_bindgen(js_name = "setWidth", skip_jsdoc)]
+    pub fn set_width(&self, width: u32) -> Button {
+        impl_method!(self.set_width(width));
+    }
+
+    /// Set the height of the button in pixels.
+    ///
+    /// @param {number} height - The button height in pixels.
+    /// @returns {Button} - The Button object.
+    #[wasm_bindgen(js_name = "setHeight", skip_jsdoc)]
+    pub fn set_height(&self, height: u32) -> Button {
+        impl_method!(self.set_height(height));
+    }
+
+    /// Set the alt text for the button to help accessibility.
+    ///
+    /// The alt text is used with screen readers to help people with visual
+    /// disabilities.
+    ///
+    /// @param {string} alt_text - The alt text string to add to the button.
+    /// @returns {Button} - The Button object.
+    #[wasm_bindgen(js_name = "setAltText", skip_jsdoc)]
+    pub fn set_alt_text(&self, alt_text: &str) -> Button {
+        impl_method!(self.set_alt_text(alt_text));
+    }
+
+    /// Set the object movement options for a worksheet button.
+    ///
+    /// Set the option to define how a button will behave in Excel if the cells
+    /// under the button are moved, deleted, or have their size changed. In
+    /// Excel the options are:
+    ///
+    /// 1. Move and size with cells.
+    /// 2. Move but don't size with cells.
+    /// 3. Don't move or size with cells.
+    ///
+    /// @param {ObjectMovement} option - The object movement option.
+    /// @returns {Button} - The Button object.
+    #[wasm_bindgen(js_name = "setObjectMovement", skip_jsdoc)]
+    pub fn set_object_movement(&self, option: ObjectMovement) -> Button {
+        impl_method!(self.set_object_movement(option.into()));
+    }
+}
@@ -25,8 +25,9 @@ mod chart_gradient_stop;
 use std::sync::{Arc, Mutex};
 
 use chart_axis::ChartAxis;
-use chart_empty_cells::ChartEmptyCells;
+pub(crate) use chart_empty_cells::ChartEmptyCells;
 use chart_legend::ChartLegend;
+pub(crate) use chart_range::ChartRange;
 use chart_series::ChartSeries;
 use chart_title::ChartTitle;
 use chart_type::ChartType;
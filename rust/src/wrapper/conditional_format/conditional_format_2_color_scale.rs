@@ -0,0 +1,137 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::color::Color;
+use crate::wrapper::WasmResult;
+
+use super::{
+    conditional_format_type::ConditionalFormatType,
+    conditional_format_value::JsConditionalFormatValue,
+};
+
+/// The `ConditionalFormat2ColorScale` struct represents a 2 Color Scale
+/// conditional format.
+///
+/// `ConditionalFormat2ColorScale` is used to represent a Cell style
+/// conditional format in Excel. A 2 Color Scale Cell conditional format shows
+/// a per cell color gradient from the minimum value to the maximum value.
+///
+/// <img
+/// src="https://rustxlsxwriter.github.io/images/conditional_format_2color_intro.png">
+///
+/// It is used in conjunction with the {@link Worksheet#addConditionalFormat}
+/// method.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ConditionalFormat2ColorScale {
+    pub(crate) inner: Arc<Mutex<xlsx::ConditionalFormat2ColorScale>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ConditionalFormat2ColorScale::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ConditionalFormat2ColorScale {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ConditionalFormat2ColorScale {
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, xlsx::ConditionalFormat2ColorScale> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Create a new 2 Color Scale conditional format struct.
+    ///
+    /// By default the scale goes from the lowest value in the range in yellow
+    /// (`#FFEF9C`) to the highest value in green (`#63BE7B`).
+    ///
+    /// @returns {ConditionalFormat2ColorScale} - The conditional format object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ConditionalFormat2ColorScale {
+        ConditionalFormat2ColorScale {
+            inner: Arc::new(Mutex::new(xlsx::ConditionalFormat2ColorScale::new())),
+        }
+    }
+
+    /// Set the type and value of the minimum in the 2 color scale.
+    ///
+    /// Set the minimum type (number, percent, formula or percentile) and value
+    /// for a 2 color scale type of conditional format. By default the minimum
+    /// is the lowest value in the conditional formatting range.
+    ///
+    /// String values are ignored in this type of conditional format. Use a
+    /// {@link Formula} for cell references.
+    ///
+    /// @param {ConditionalFormatType} rule_type - The type of the minimum value.
+    /// @param {ConditionalFormatValue} value - The minimum value.
+    /// @returns {ConditionalFormat2ColorScale} - The conditional format object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - The value isn't a string, number, boolean, date
+    ///   or formula.
+    #[wasm_bindgen(js_name = "setMinimum", skip_jsdoc)]
+    pub fn set_minimum(
+        &self,
+        rule_type: ConditionalFormatType,
+        value: &JsConditionalFormatValue,
+    ) -> WasmResult<ConditionalFormat2ColorScale> {
+        let value: xlsx::ConditionalFormatValue = value.try_into()?;
+        Ok(impl_method!(self.set_minimum(rule_type.into(), value)))
+    }
+
+    /// Set the type and value of the maximum in the 2 color scale.
+    ///
+    /// Set the maximum type (number, percent, formula or percentile) and value
+    /// for a 2 color scale type of conditional format. By default the maximum
+    /// is the highest value in the conditional formatting range.
+    ///
+    /// @param {ConditionalFormatType} rule_type - The type of the maximum value.
+    /// @param {ConditionalFormatValue} value - The maximum value.
+    /// @returns {ConditionalFormat2ColorScale} - The conditional format object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - The value isn't a string, number, boolean, date
+    ///   or formula.
+    #[wasm_bindgen(js_name = "setMaximum", skip_jsdoc)]
+    pub fn set_maximum(
+        &self,
+        rule_type: ConditionalFormatType,
+        value: &JsConditionalFormatValue,
+    ) -> WasmResult<ConditionalFormat2ColorScale> {
+        let value: xlsx::ConditionalFormatValue = value.try_into()?;
+        Ok(impl_method!(self.set_maximum(rule_type.into(), value)))
+    }
+
+    /// Set the color of the minimum in the 2 color scale.
+    ///
+    /// By default the minimum color is `#FFEF9C` (yellow).
+    ///
+    /// @param {Color} color - The minimum color.
+    /// @returns {ConditionalFormat2ColorScale} - The conditional format object.
+    #[wasm_bindgen(js_name = "setMinimumColor", skip_jsdoc)]
+    pub fn set_minimum_color(&self, color: &Color) -> ConditionalFormat2ColorScale {
+        impl_method!(self.set_minimum_color(color.inner))
+    }
+
+    /// Set the color of the maximum in the 2 color scale.
+    ///
+    /// By default the maximum color is `#63BE7B` (green).
+    ///
+    /// @param {Color} color - The maximum color.
+    /// @returns {ConditionalFormat2ColorScale} - The conditional format object.
+    #[wasm_bindgen(js_name = "setMaximumColor", skip_jsdoc)]
+    pub fn set_maximum_color(&self, color: &Color) -> ConditionalFormat2ColorScale {
+        impl_method!(self.set_maximum_color(color.inner))
+    }
+}
@@ -0,0 +1,79 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::format::Format;
+
+use super::conditional_format_average_rule::ConditionalFormatAverageRule;
+
+/// The `ConditionalFormatAverage` struct represents an Average/Standard
+/// Deviation style conditional format.
+///
+/// `ConditionalFormatAverage` is used to represent a Average or Standard
+/// Deviation style conditional format in Excel.
+///
+/// <img
+/// src="https://rustxlsxwriter.github.io/images/conditional_format_average_intro.png">
+///
+/// It is used in conjunction with the {@link Worksheet#addConditionalFormat}
+/// method.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ConditionalFormatAverage {
+    pub(crate) inner: Arc<Mutex<xlsx::ConditionalFormatAverage>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ConditionalFormatAverage::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ConditionalFormatAverage {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ConditionalFormatAverage {
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, xlsx::ConditionalFormatAverage> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Create a new Average conditional format struct.
+    ///
+    /// @returns {ConditionalFormatAverage} - The conditional format object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ConditionalFormatAverage {
+        ConditionalFormatAverage {
+            inner: Arc::new(Mutex::new(xlsx::ConditionalFormatAverage::new())),
+        }
+    }
+
+    /// Set the rule for the Average conditional format such as "above average"
+    /// or "1 standard deviation below".
+    ///
+    /// @param {ConditionalFormatAverageRule} rule - The rule.
+    /// @returns {ConditionalFormatAverage} - The conditional format object.
+    #[wasm_bindgen(js_name = "setRule", skip_jsdoc)]
+    pub fn set_rule(&self, rule: ConditionalFormatAverageRule) -> ConditionalFormatAverage {
+        impl_method!(self.set_rule(rule.into()))
+    }
+
+    /// Set the {@link Format} of the conditional format rule.
+    ///
+    /// Not all cell format properties can be set in a conditional format. For
+    /// example the font name and size, or the alignment, are ignored by Excel.
+    ///
+    /// @param {Format} format - The format applied to the cells that match the
+    ///   rule.
+    /// @returns {ConditionalFormatAverage} - The conditional format object.
+    #[wasm_bindgen(js_name = "setFormat", skip_jsdoc)]
+    pub fn set_format(&self, format: &Format) -> ConditionalFormatAverage {
+        impl_method!(self.set_format(format.lock().clone()))
+    }
+}
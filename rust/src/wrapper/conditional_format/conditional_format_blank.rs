@@ -0,0 +1,77 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::format::Format;
+
+/// The `ConditionalFormatBlank` struct represents a Blank/Non-blank conditional
+/// format.
+///
+/// `ConditionalFormatBlank` is used to represent a Blank or Non-blank style
+/// conditional format in Excel. A Blank conditional format highlights blank
+/// values in a range while the inverted version highlights non-blanks values.
+///
+/// <img
+/// src="https://rustxlsxwriter.github.io/images/conditional_format_blank_intro.png">
+///
+/// It is used in conjunction with the {@link Worksheet#addConditionalFormat}
+/// method.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ConditionalFormatBlank {
+    pub(crate) inner: Arc<Mutex<xlsx::ConditionalFormatBlank>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ConditionalFormatBlank::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ConditionalFormatBlank {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ConditionalFormatBlank {
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, xlsx::ConditionalFormatBlank> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Create a new Blank/Non-blank conditional format struct.
+    ///
+    /// @returns {ConditionalFormatBlank} - The conditional format object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ConditionalFormatBlank {
+        ConditionalFormatBlank {
+            inner: Arc::new(Mutex::new(xlsx::ConditionalFormatBlank::new())),
+        }
+    }
+
+    /// Invert the conditional format to show non-blank values instead of
+    /// blanks.
+    ///
+    /// @returns {ConditionalFormatBlank} - The conditional format object.
+    #[wasm_bindgen(skip_jsdoc)]
+    pub fn invert(&self) -> ConditionalFormatBlank {
+        impl_method!(self.invert())
+    }
+
+    /// Set the {@link Format} of the conditional format rule.
+    ///
+    /// Not all cell format properties can be set in a conditional format. For
+    /// example the font name and size, or the alignment, are ignored by Excel.
+    ///
+    /// @param {Format} format - The format applied to the cells that match the
+    ///   rule.
+    /// @returns {ConditionalFormatBlank} - The conditional format object.
+    #[wasm_bindgen(js_name = "setFormat", skip_jsdoc)]
+    pub fn set_format(&self, format: &Format) -> ConditionalFormatBlank {
+        impl_method!(self.set_format(format.lock().clone()))
+    }
+}
@@ -0,0 +1,79 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::format::Format;
+
+use super::conditional_format_cell_rule::ConditionalFormatCellRule;
+
+/// The `ConditionalFormatCell` struct represents a Cell conditional format.
+///
+/// `ConditionalFormatCell` is used to represent a Cell style conditional format
+/// in Excel. Cell conditional formats use simple equalities such as "equal to"
+/// or "greater than" or "between".
+///
+/// <img
+/// src="https://rustxlsxwriter.github.io/images/conditional_format_cell_intro.png">
+///
+/// It is used in conjunction with the {@link Worksheet#addConditionalFormat}
+/// method.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ConditionalFormatCell {
+    pub(crate) inner: Arc<Mutex<xlsx::ConditionalFormatCell>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ConditionalFormatCell::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ConditionalFormatCell {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ConditionalFormatCell {
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, xlsx::ConditionalFormatCell> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Create a new Cell conditional format struct.
+    ///
+    /// @returns {ConditionalFormatCell} - The conditional format object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ConditionalFormatCell {
+        ConditionalFormatCell {
+            inner: Arc::new(Mutex::new(xlsx::ConditionalFormatCell::new())),
+        }
+    }
+
+    /// Set the rule for the Cell conditional format such as "greater than"
+    /// or "between".
+    ///
+    /// @param {ConditionalFormatCellRule} rule - The rule.
+    /// @returns {ConditionalFormatCell} - The conditional format object.
+    #[wasm_bindgen(js_name = "setRule", skip_jsdoc)]
+    pub fn set_rule(&self, rule: &ConditionalFormatCellRule) -> ConditionalFormatCell {
+        impl_method!(self.set_rule(rule.inner.clone()))
+    }
+
+    /// Set the {@link Format} of the conditional format rule.
+    ///
+    /// Not all cell format properties can be set in a conditional format. For
+    /// example the font name and size, or the alignment, are ignored by Excel.
+    ///
+    /// @param {Format} format - The format applied to the cells that match the
+    ///   rule.
+    /// @returns {ConditionalFormatCell} - The conditional format object.
+    #[wasm_bindgen(js_name = "setFormat", skip_jsdoc)]
+    pub fn set_format(&self, format: &Format) -> ConditionalFormatCell {
+        impl_method!(self.set_format(format.lock().clone()))
+    }
+}
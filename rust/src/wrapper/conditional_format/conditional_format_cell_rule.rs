@@ -0,0 +1,160 @@
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::WasmResult;
+
+use super::conditional_format_value::JsConditionalFormatValue;
+
+/// The `ConditionalFormatCellRule` struct defines the conditional format rule
+/// for {@link ConditionalFormatCell}.
+///
+/// The rule operands can be numbers, strings, booleans, dates or a
+/// {@link Formula} for cell references such as `"=$B$1"`.
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ConditionalFormatCellRule {
+    pub(crate) inner: xlsx::ConditionalFormatCellRule<xlsx::ConditionalFormatValue>,
+}
+
+#[wasm_bindgen]
+impl ConditionalFormatCellRule {
+    /// Show the conditional format for cells that are equal to the target value.
+    ///
+    /// @param {ConditionalFormatValue} value - The target value.
+    /// @returns {ConditionalFormatCellRule} - The rule object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - The value isn't a string, number, boolean, date
+    ///   or formula.
+    #[wasm_bindgen(js_name = "equalTo", skip_jsdoc)]
+    pub fn equal_to(value: &JsConditionalFormatValue) -> WasmResult<ConditionalFormatCellRule> {
+        Ok(ConditionalFormatCellRule {
+            inner: xlsx::ConditionalFormatCellRule::EqualTo(value.try_into()?),
+        })
+    }
+
+    /// Show the conditional format for cells that are not equal to the target value.
+    ///
+    /// @param {ConditionalFormatValue} value - The target value.
+    /// @returns {ConditionalFormatCellRule} - The rule object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - The value isn't a string, number, boolean, date
+    ///   or formula.
+    #[wasm_bindgen(js_name = "notEqualTo", skip_jsdoc)]
+    pub fn not_equal_to(value: &JsConditionalFormatValue) -> WasmResult<ConditionalFormatCellRule> {
+        Ok(ConditionalFormatCellRule {
+            inner: xlsx::ConditionalFormatCellRule::NotEqualTo(value.try_into()?),
+        })
+    }
+
+    /// Show the conditional format for cells that are greater than the target value.
+    ///
+    /// @param {ConditionalFormatValue} value - The target value.
+    /// @returns {ConditionalFormatCellRule} - The rule object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - The value isn't a string, number, boolean, date
+    ///   or formula.
+    #[wasm_bindgen(js_name = "greaterThan", skip_jsdoc)]
+    pub fn greater_than(value: &JsConditionalFormatValue) -> WasmResult<ConditionalFormatCellRule> {
+        Ok(ConditionalFormatCellRule {
+            inner: xlsx::ConditionalFormatCellRule::GreaterThan(value.try_into()?),
+        })
+    }
+
+    /// Show the conditional format for cells that are greater than or equal to the target value.
+    ///
+    /// @param {ConditionalFormatValue} value - The target value.
+    /// @returns {ConditionalFormatCellRule} - The rule object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - The value isn't a string, number, boolean, date
+    ///   or formula.
+    #[wasm_bindgen(js_name = "greaterThanOrEqualTo", skip_jsdoc)]
+    pub fn greater_than_or_equal_to(
+        value: &JsConditionalFormatValue,
+    ) -> WasmResult<ConditionalFormatCellRule> {
+        Ok(ConditionalFormatCellRule {
+            inner: xlsx::ConditionalFormatCellRule::GreaterThanOrEqualTo(value.try_into()?),
+        })
+    }
+
+    /// Show the conditional format for cells that are less than the target value.
+    ///
+    /// @param {ConditionalFormatValue} value - The target value.
+    /// @returns {ConditionalFormatCellRule} - The rule object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - The value isn't a string, number, boolean, date
+    ///   or formula.
+    #[wasm_bindgen(js_name = "lessThan", skip_jsdoc)]
+    pub fn less_than(value: &JsConditionalFormatValue) -> WasmResult<ConditionalFormatCellRule> {
+        Ok(ConditionalFormatCellRule {
+            inner: xlsx::ConditionalFormatCellRule::LessThan(value.try_into()?),
+        })
+    }
+
+    /// Show the conditional format for cells that are less than or equal to the target value.
+    ///
+    /// @param {ConditionalFormatValue} value - The target value.
+    /// @returns {ConditionalFormatCellRule} - The rule object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - The value isn't a string, number, boolean, date
+    ///   or formula.
+    #[wasm_bindgen(js_name = "lessThanOrEqualTo", skip_jsdoc)]
+    pub fn less_than_or_equal_to(
+        value: &JsConditionalFormatValue,
+    ) -> WasmResult<ConditionalFormatCellRule> {
+        Ok(ConditionalFormatCellRule {
+            inner: xlsx::ConditionalFormatCellRule::LessThanOrEqualTo(value.try_into()?),
+        })
+    }
+
+    /// Show the conditional format for cells that are between the target values.
+    ///
+    /// @param {ConditionalFormatValue} min - The lower target value.
+    /// @param {ConditionalFormatValue} max - The upper target value.
+    /// @returns {ConditionalFormatCellRule} - The rule object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - A value isn't a string, number, boolean, date or
+    ///   formula.
+    #[wasm_bindgen(js_name = "between", skip_jsdoc)]
+    pub fn between(
+        min: &JsConditionalFormatValue,
+        max: &JsConditionalFormatValue,
+    ) -> WasmResult<ConditionalFormatCellRule> {
+        Ok(ConditionalFormatCellRule {
+            inner: xlsx::ConditionalFormatCellRule::Between(min.try_into()?, max.try_into()?),
+        })
+    }
+
+    /// Show the conditional format for cells that are not between the target values.
+    ///
+    /// @param {ConditionalFormatValue} min - The lower target value.
+    /// @param {ConditionalFormatValue} max - The upper target value.
+    /// @returns {ConditionalFormatCellRule} - The rule object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - A value isn't a string, number, boolean, date or
+    ///   formula.
+    #[wasm_bindgen(js_name = "notBetween", skip_jsdoc)]
+    pub fn not_between(
+        min: &JsConditionalFormatValue,
+        max: &JsConditionalFormatValue,
+    ) -> WasmResult<ConditionalFormatCellRule> {
+        Ok(ConditionalFormatCellRule {
+            inner: xlsx::ConditionalFormatCellRule::NotBetween(min.try_into()?, max.try_into()?),
+        })
+    }
+}
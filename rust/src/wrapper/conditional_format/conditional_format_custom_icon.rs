@@ -0,0 +1,126 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::WasmResult;
+
+use super::{
+    conditional_format_icon_type::ConditionalFormatIconType,
+    conditional_format_type::ConditionalFormatType,
+    conditional_format_value::JsConditionalFormatValue,
+};
+
+/// The `ConditionalFormatCustomIcon` struct represents an icon in an Icon Set
+/// style conditional format.
+///
+/// `ConditionalFormatCustomIcon` is used to define the rule for each icon in
+/// a {@link ConditionalFormatIconSet} and, optionally, to replace the default
+/// icon with an icon from another icon set or with no icon at all.
+///
+/// <img
+/// src="https://rustxlsxwriter.github.io/images/conditional_format_icon_custom_dialog.png">
+///
+/// It is used in conjunction with the {@link ConditionalFormatIconSet#setIcons}
+/// method.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ConditionalFormatCustomIcon {
+    pub(crate) inner: Arc<Mutex<xlsx::ConditionalFormatCustomIcon>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ConditionalFormatCustomIcon::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ConditionalFormatCustomIcon {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ConditionalFormatCustomIcon {
+    /// Create a new Custom Icon struct for an Icon Set style conditional
+    /// format.
+    ///
+    /// @returns {ConditionalFormatCustomIcon} - The custom icon object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ConditionalFormatCustomIcon {
+        ConditionalFormatCustomIcon {
+            inner: Arc::new(Mutex::new(xlsx::ConditionalFormatCustomIcon::new())),
+        }
+    }
+
+    /// Set the rule for the custom icon.
+    ///
+    /// Set the rule type (number, percent, formula or percentile) and value
+    /// above which the icon is displayed. The rule of the first icon in a set
+    /// is always ">= 0%" and can't be changed.
+    ///
+    /// String values are ignored in this type of conditional format. Use a
+    /// {@link Formula} for cell references.
+    ///
+    /// @param {ConditionalFormatType} rule_type - The type of the rule value.
+    /// @param {ConditionalFormatValue} value - The rule value.
+    /// @returns {ConditionalFormatCustomIcon} - The custom icon object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - The value isn't a string, number, boolean, date
+    ///   or formula.
+    #[wasm_bindgen(js_name = "setRule", skip_jsdoc)]
+    pub fn set_rule(
+        &self,
+        rule_type: ConditionalFormatType,
+        value: &JsConditionalFormatValue,
+    ) -> WasmResult<ConditionalFormatCustomIcon> {
+        let value: xlsx::ConditionalFormatValue = value.try_into()?;
+        Ok(impl_method!(self.set_rule(rule_type.into(), value)))
+    }
+
+    /// Set a custom icon type.
+    ///
+    /// Replace the default icon with the icon at `index` in another icon type.
+    /// For example the fully filled box icon in
+    /// {@link ConditionalFormatIconType.FiveBoxes} has index 4.
+    ///
+    /// An index that is out of range for the icon type is ignored.
+    ///
+    /// @param {ConditionalFormatIconType} icon_type - The icon type to take the
+    ///   icon from.
+    /// @param {number} index - Index to the icon within the type, starting at
+    ///   0.
+    /// @returns {ConditionalFormatCustomIcon} - The custom icon object.
+    #[wasm_bindgen(js_name = "setIconType", skip_jsdoc)]
+    pub fn set_icon_type(
+        &self,
+        icon_type: ConditionalFormatIconType,
+        index: u8,
+    ) -> ConditionalFormatCustomIcon {
+        impl_method!(self.set_icon_type(icon_type.into(), index))
+    }
+
+    /// Turn off the icon in the cell.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {ConditionalFormatCustomIcon} - The custom icon object.
+    #[wasm_bindgen(js_name = "setNoIcon", skip_jsdoc)]
+    pub fn set_no_icon(&self, enable: bool) -> ConditionalFormatCustomIcon {
+        impl_method!(self.set_no_icon(enable))
+    }
+
+    /// Set the rule to be "greater than" instead of the Excel default of
+    /// "greater than or equal to".
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {ConditionalFormatCustomIcon} - The custom icon object.
+    #[wasm_bindgen(js_name = "setGreaterThan", skip_jsdoc)]
+    pub fn set_greater_than(&self, enable: bool) -> ConditionalFormatCustomIcon {
+        impl_method!(self.set_greater_than(enable))
+    }
+}
@@ -0,0 +1,245 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::color::Color;
+use crate::wrapper::WasmResult;
+
+use super::{
+    conditional_format_data_bar_axis_position::ConditionalFormatDataBarAxisPosition,
+    conditional_format_data_bar_direction::ConditionalFormatDataBarDirection,
+    conditional_format_type::ConditionalFormatType,
+    conditional_format_value::JsConditionalFormatValue,
+};
+
+/// The `ConditionalFormatDataBar` struct represents a Data Bar conditional
+/// format.
+///
+/// `ConditionalFormatDataBar` is used to represent a Cell style conditional
+/// format in Excel. A Data Bar Cell conditional format shows a per cell color
+/// gradient from the minimum value to the maximum value.
+///
+/// <img
+/// src="https://rustxlsxwriter.github.io/images/conditional_format_databar_intro.png">
+///
+/// It is used in conjunction with the {@link Worksheet#addConditionalFormat}
+/// method.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ConditionalFormatDataBar {
+    pub(crate) inner: Arc<Mutex<xlsx::ConditionalFormatDataBar>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ConditionalFormatDataBar::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ConditionalFormatDataBar {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ConditionalFormatDataBar {
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, xlsx::ConditionalFormatDataBar> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Create a new Data Bar conditional format struct.
+    ///
+    /// By default the data bar uses a blue (`#638EC6`) gradient fill with a
+    /// solid border, in the Excel 2010+ style.
+    ///
+    /// @returns {ConditionalFormatDataBar} - The conditional format object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ConditionalFormatDataBar {
+        ConditionalFormatDataBar {
+            inner: Arc::new(Mutex::new(xlsx::ConditionalFormatDataBar::new())),
+        }
+    }
+
+    /// Set the type and value of the minimum in the data bar.
+    ///
+    /// Set the minimum type (number, percent, formula or percentile) and value
+    /// for a data bar type of conditional format. By default the minimum is
+    /// the lowest value in the conditional formatting range.
+    ///
+    /// @param {ConditionalFormatType} rule_type - The type of the minimum value.
+    /// @param {ConditionalFormatValue} value - The minimum value.
+    /// @returns {ConditionalFormatDataBar} - The conditional format object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - The value isn't a string, number, boolean, date
+    ///   or formula.
+    #[wasm_bindgen(js_name = "setMinimum", skip_jsdoc)]
+    pub fn set_minimum(
+        &self,
+        rule_type: ConditionalFormatType,
+        value: &JsConditionalFormatValue,
+    ) -> WasmResult<ConditionalFormatDataBar> {
+        let value: xlsx::ConditionalFormatValue = value.try_into()?;
+        Ok(impl_method!(self.set_minimum(rule_type.into(), value)))
+    }
+
+    /// Set the type and value of the maximum in the data bar.
+    ///
+    /// Set the maximum type (number, percent, formula or percentile) and value
+    /// for a data bar type of conditional format. By default the maximum is
+    /// the highest value in the conditional formatting range.
+    ///
+    /// @param {ConditionalFormatType} rule_type - The type of the maximum value.
+    /// @param {ConditionalFormatValue} value - The maximum value.
+    /// @returns {ConditionalFormatDataBar} - The conditional format object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - The value isn't a string, number, boolean, date
+    ///   or formula.
+    #[wasm_bindgen(js_name = "setMaximum", skip_jsdoc)]
+    pub fn set_maximum(
+        &self,
+        rule_type: ConditionalFormatType,
+        value: &JsConditionalFormatValue,
+    ) -> WasmResult<ConditionalFormatDataBar> {
+        let value: xlsx::ConditionalFormatValue = value.try_into()?;
+        Ok(impl_method!(self.set_maximum(rule_type.into(), value)))
+    }
+
+    /// Set the color of the fill in the data bar.
+    ///
+    /// By default the fill color is `#638EC6` (blue).
+    ///
+    /// @param {Color} color - The fill color.
+    /// @returns {ConditionalFormatDataBar} - The conditional format object.
+    #[wasm_bindgen(js_name = "setFillColor", skip_jsdoc)]
+    pub fn set_fill_color(&self, color: &Color) -> ConditionalFormatDataBar {
+        impl_method!(self.set_fill_color(color.inner))
+    }
+
+    /// Set the color of the border in the data bar.
+    ///
+    /// By default the border color is the same as the fill color.
+    ///
+    /// @param {Color} color - The border color.
+    /// @returns {ConditionalFormatDataBar} - The conditional format object.
+    #[wasm_bindgen(js_name = "setBorderColor", skip_jsdoc)]
+    pub fn set_border_color(&self, color: &Color) -> ConditionalFormatDataBar {
+        impl_method!(self.set_border_color(color.inner))
+    }
+
+    /// Set the color of the fill for negative values in the data bar.
+    ///
+    /// By default the negative fill color is `#FF0000` (red).
+    ///
+    /// @param {Color} color - The negative fill color.
+    /// @returns {ConditionalFormatDataBar} - The conditional format object.
+    #[wasm_bindgen(js_name = "setNegativeFillColor", skip_jsdoc)]
+    pub fn set_negative_fill_color(&self, color: &Color) -> ConditionalFormatDataBar {
+        impl_method!(self.set_negative_fill_color(color.inner))
+    }
+
+    /// Set the color of the border for negative values in the data bar.
+    ///
+    /// By default the negative border color is `#FF0000` (red).
+    ///
+    /// @param {Color} color - The negative border color.
+    /// @returns {ConditionalFormatDataBar} - The conditional format object.
+    #[wasm_bindgen(js_name = "setNegativeBorderColor", skip_jsdoc)]
+    pub fn set_negative_border_color(&self, color: &Color) -> ConditionalFormatDataBar {
+        impl_method!(self.set_negative_border_color(color.inner))
+    }
+
+    /// Set the data bar fill to solid.
+    ///
+    /// By default Excel uses a gradient fill for data bars.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {ConditionalFormatDataBar} - The conditional format object.
+    #[wasm_bindgen(js_name = "setSolidFill", skip_jsdoc)]
+    pub fn set_solid_fill(&self, enable: bool) -> ConditionalFormatDataBar {
+        impl_method!(self.set_solid_fill(enable))
+    }
+
+    /// Turn off the border for a data bar conditional format.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {ConditionalFormatDataBar} - The conditional format object.
+    #[wasm_bindgen(js_name = "setBorderOff", skip_jsdoc)]
+    pub fn set_border_off(&self, enable: bool) -> ConditionalFormatDataBar {
+        impl_method!(self.set_border_off(enable))
+    }
+
+    /// Set the direction of the data bar conditional format.
+    ///
+    /// Set the data bar direction to "Right to left", "Left to right" or
+    /// "Context". The default is "Context", which depends on the reading
+    /// direction of the worksheet.
+    ///
+    /// @param {ConditionalFormatDataBarDirection} direction - The direction of
+    ///   the data bar.
+    /// @returns {ConditionalFormatDataBar} - The conditional format object.
+    #[wasm_bindgen(js_name = "setDirection", skip_jsdoc)]
+    pub fn set_direction(
+        &self,
+        direction: ConditionalFormatDataBarDirection,
+    ) -> ConditionalFormatDataBar {
+        impl_method!(self.set_direction(direction.into()))
+    }
+
+    /// Set the data bar to show the bar only and hide the cell data.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {ConditionalFormatDataBar} - The conditional format object.
+    #[wasm_bindgen(js_name = "setBarOnly", skip_jsdoc)]
+    pub fn set_bar_only(&self, enable: bool) -> ConditionalFormatDataBar {
+        impl_method!(self.set_bar_only(enable))
+    }
+
+    /// Set the position of the axis in a data bar.
+    ///
+    /// The axis is only displayed when the range contains negative values.
+    ///
+    /// @param {ConditionalFormatDataBarAxisPosition} position - The position of
+    ///   the axis.
+    /// @returns {ConditionalFormatDataBar} - The conditional format object.
+    #[wasm_bindgen(js_name = "setAxisPosition", skip_jsdoc)]
+    pub fn set_axis_position(
+        &self,
+        position: ConditionalFormatDataBarAxisPosition,
+    ) -> ConditionalFormatDataBar {
+        impl_method!(self.set_axis_position(position.into()))
+    }
+
+    /// Set the color of the axis in the data bar.
+    ///
+    /// By default the axis color is `#000000` (black).
+    ///
+    /// @param {Color} color - The axis color.
+    /// @returns {ConditionalFormatDataBar} - The conditional format object.
+    #[wasm_bindgen(js_name = "setAxisColor", skip_jsdoc)]
+    pub fn set_axis_color(&self, color: &Color) -> ConditionalFormatDataBar {
+        impl_method!(self.set_axis_color(color.inner))
+    }
+
+    /// Set the data bar format to the original Excel 2007 style.
+    ///
+    /// The original Excel 2007 style was simpler than the post Excel 2010 style
+    /// and had very limited configuration options. Properties such as the
+    /// border, solid fill, direction and axis are not available in this style.
+    ///
+    /// This is mainly intended for backward compatibility with older versions
+    /// of Excel.
+    ///
+    /// @returns {ConditionalFormatDataBar} - The conditional format object.
+    #[wasm_bindgen(js_name = "useClassicStyle", skip_jsdoc)]
+    pub fn use_classic_style(&self) -> ConditionalFormatDataBar {
+        impl_method!(self.use_classic_style())
+    }
+}
@@ -0,0 +1,37 @@
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+/// The `ConditionalFormatDataBarAxisPosition` enum defines the conditional
+/// format axis positions for {@link ConditionalFormatDataBar}.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[wasm_bindgen]
+pub enum ConditionalFormatDataBarAxisPosition {
+    /// The axis is set automatically depending on whether the data contains
+    /// negative values. This is the default.
+    Automatic,
+
+    /// The axis is set at the midpoint. This is the automatic option for ranges
+    /// with negative values.
+    Midpoint,
+
+    /// Turn the axis off.
+    None,
+}
+
+impl From<ConditionalFormatDataBarAxisPosition> for xlsx::ConditionalFormatDataBarAxisPosition {
+    fn from(
+        position: ConditionalFormatDataBarAxisPosition,
+    ) -> xlsx::ConditionalFormatDataBarAxisPosition {
+        match position {
+            ConditionalFormatDataBarAxisPosition::Automatic => {
+                xlsx::ConditionalFormatDataBarAxisPosition::Automatic
+            }
+            ConditionalFormatDataBarAxisPosition::Midpoint => {
+                xlsx::ConditionalFormatDataBarAxisPosition::Midpoint
+            }
+            ConditionalFormatDataBarAxisPosition::None => {
+                xlsx::ConditionalFormatDataBarAxisPosition::None
+            }
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+/// The `ConditionalFormatDataBarDirection` enum defines the conditional format
+/// directions for {@link ConditionalFormatDataBar}.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[wasm_bindgen]
+pub enum ConditionalFormatDataBarDirection {
+    /// The bars go "Right to left" or "Left to right" depending on the context.
+    /// This is the default.
+    Context,
+
+    /// The bars go "Left to right".
+    LeftToRight,
+
+    /// The bars go "Right to left".
+    RightToLeft,
+}
+
+impl From<ConditionalFormatDataBarDirection> for xlsx::ConditionalFormatDataBarDirection {
+    fn from(
+        direction: ConditionalFormatDataBarDirection,
+    ) -> xlsx::ConditionalFormatDataBarDirection {
+        match direction {
+            ConditionalFormatDataBarDirection::Context => {
+                xlsx::ConditionalFormatDataBarDirection::Context
+            }
+            ConditionalFormatDataBarDirection::LeftToRight => {
+                xlsx::ConditionalFormatDataBarDirection::LeftToRight
+            }
+            ConditionalFormatDataBarDirection::RightToLeft => {
+                xlsx::ConditionalFormatDataBarDirection::RightToLeft
+            }
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::format::Format;
+
+use super::conditional_format_date_rule::ConditionalFormatDateRule;
+
+/// The `ConditionalFormatDate` struct represents a Dates Occurring style
+/// conditional format.
+///
+/// `ConditionalFormatDate` is used to represent a Dates Occurring style
+/// conditional format in Excel. This is used to identify dates in ranges like
+/// "Last Week" or "Last Month".
+///
+/// <img
+/// src="https://rustxlsxwriter.github.io/images/conditional_format_date_intro.png">
+///
+/// It is used in conjunction with the {@link Worksheet#addConditionalFormat}
+/// method.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ConditionalFormatDate {
+    pub(crate) inner: Arc<Mutex<xlsx::ConditionalFormatDate>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ConditionalFormatDate::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ConditionalFormatDate {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ConditionalFormatDate {
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, xlsx::ConditionalFormatDate> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Create a new Dates Occurring conditional format struct.
+    ///
+    /// @returns {ConditionalFormatDate} - The conditional format object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ConditionalFormatDate {
+        ConditionalFormatDate {
+            inner: Arc::new(Mutex::new(xlsx::ConditionalFormatDate::new())),
+        }
+    }
+
+    /// Set the rule for the Dates Occurring conditional format such as
+    /// "last week" or "this month".
+    ///
+    /// @param {ConditionalFormatDateRule} rule - The rule.
+    /// @returns {ConditionalFormatDate} - The conditional format object.
+    #[wasm_bindgen(js_name = "setRule", skip_jsdoc)]
+    pub fn set_rule(&self, rule: ConditionalFormatDateRule) -> ConditionalFormatDate {
+        impl_method!(self.set_rule(rule.into()))
+    }
+
+    /// Set the {@link Format} of the conditional format rule.
+    ///
+    /// Not all cell format properties can be set in a conditional format. For
+    /// example the font name and size, or the alignment, are ignored by Excel.
+    ///
+    /// @param {Format} format - The format applied to the cells that match the
+    ///   rule.
+    /// @returns {ConditionalFormatDate} - The conditional format object.
+    #[wasm_bindgen(js_name = "setFormat", skip_jsdoc)]
+    pub fn set_format(&self, format: &Format) -> ConditionalFormatDate {
+        impl_method!(self.set_format(format.lock().clone()))
+    }
+}
@@ -0,0 +1,65 @@
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+/// The `ConditionalFormatDateRule` enum defines the conditional format
+/// criteria for {@link ConditionalFormatDate}.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[wasm_bindgen]
+pub enum ConditionalFormatDateRule {
+    /// Show the conditional format for dates occurring yesterday. This is the
+    /// default.
+    Yesterday,
+
+    /// Show the conditional format for dates occurring today, relative to when
+    /// the file is opened.
+    Today,
+
+    /// Show the conditional format for dates occurring tomorrow, relative to
+    /// when the file is opened.
+    Tomorrow,
+
+    /// Show the conditional format for dates occurring in the last 7 days,
+    /// relative to when the file is opened.
+    Last7Days,
+
+    /// Show the conditional format for dates occurring in the last week,
+    /// relative to when the file is opened.
+    LastWeek,
+
+    /// Show the conditional format for dates occurring this week, relative to
+    /// when the file is opened.
+    ThisWeek,
+
+    /// Show the conditional format for dates occurring in the next week,
+    /// relative to when the file is opened.
+    NextWeek,
+
+    /// Show the conditional format for dates occurring in the last month,
+    /// relative to when the file is opened.
+    LastMonth,
+
+    /// Show the conditional format for dates occurring this month, relative to
+    /// when the file is opened.
+    ThisMonth,
+
+    /// Show the conditional format for dates occurring in the next month,
+    /// relative to when the file is opened.
+    NextMonth,
+}
+
+impl From<ConditionalFormatDateRule> for xlsx::ConditionalFormatDateRule {
+    fn from(rule: ConditionalFormatDateRule) -> xlsx::ConditionalFormatDateRule {
+        match rule {
+            ConditionalFormatDateRule::Yesterday => xlsx::ConditionalFormatDateRule::Yesterday,
+            ConditionalFormatDateRule::Today => xlsx::ConditionalFormatDateRule::Today,
+            ConditionalFormatDateRule::Tomorrow => xlsx::ConditionalFormatDateRule::Tomorrow,
+            ConditionalFormatDateRule::Last7Days => xlsx::ConditionalFormatDateRule::Last7Days,
+            ConditionalFormatDateRule::LastWeek => xlsx::ConditionalFormatDateRule::LastWeek,
+            ConditionalFormatDateRule::ThisWeek => xlsx::ConditionalFormatDateRule::ThisWeek,
+            ConditionalFormatDateRule::NextWeek => xlsx::ConditionalFormatDateRule::NextWeek,
+            ConditionalFormatDateRule::LastMonth => xlsx::ConditionalFormatDateRule::LastMonth,
+            ConditionalFormatDateRule::ThisMonth => xlsx::ConditionalFormatDateRule::ThisMonth,
+            ConditionalFormatDateRule::NextMonth => xlsx::ConditionalFormatDateRule::NextMonth,
+        }
+    }
+}
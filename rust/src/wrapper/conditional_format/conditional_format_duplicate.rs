@@ -0,0 +1,78 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::format::Format;
+
+/// The `ConditionalFormatDuplicate` struct represents a Duplicate/Unique
+/// conditional format.
+///
+/// `ConditionalFormatDuplicate` is used to represent a Duplicate or Unique
+/// style conditional format in Excel. Duplicate conditional formats show
+/// duplicated values in a range while Unique conditional formats show unique
+/// values.
+///
+/// <img
+/// src="https://rustxlsxwriter.github.io/images/conditional_format_duplicate_intro.png">
+///
+/// It is used in conjunction with the {@link Worksheet#addConditionalFormat}
+/// method.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ConditionalFormatDuplicate {
+    pub(crate) inner: Arc<Mutex<xlsx::ConditionalFormatDuplicate>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ConditionalFormatDuplicate::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ConditionalFormatDuplicate {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ConditionalFormatDuplicate {
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, xlsx::ConditionalFormatDuplicate> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Create a new Duplicate/Unique conditional format struct.
+    ///
+    /// @returns {ConditionalFormatDuplicate} - The conditional format object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ConditionalFormatDuplicate {
+        ConditionalFormatDuplicate {
+            inner: Arc::new(Mutex::new(xlsx::ConditionalFormatDuplicate::new())),
+        }
+    }
+
+    /// Invert the conditional format to show unique values instead of
+    /// duplicates.
+    ///
+    /// @returns {ConditionalFormatDuplicate} - The conditional format object.
+    #[wasm_bindgen(skip_jsdoc)]
+    pub fn invert(&self) -> ConditionalFormatDuplicate {
+        impl_method!(self.invert())
+    }
+
+    /// Set the {@link Format} of the conditional format rule.
+    ///
+    /// Not all cell format properties can be set in a conditional format. For
+    /// example the font name and size, or the alignment, are ignored by Excel.
+    ///
+    /// @param {Format} format - The format applied to the cells that match the
+    ///   rule.
+    /// @returns {ConditionalFormatDuplicate} - The conditional format object.
+    #[wasm_bindgen(js_name = "setFormat", skip_jsdoc)]
+    pub fn set_format(&self, format: &Format) -> ConditionalFormatDuplicate {
+        impl_method!(self.set_format(format.lock().clone()))
+    }
+}
@@ -0,0 +1,77 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::format::Format;
+
+/// The `ConditionalFormatError` struct represents an Error/Non-error conditional
+/// format.
+///
+/// `ConditionalFormatError` is used to represent an Error or Non-error style
+/// conditional format in Excel. An error conditional format highlights error
+/// values in a range while the inverted version highlights non-errors values.
+///
+/// <img
+/// src="https://rustxlsxwriter.github.io/images/conditional_format_error_intro.png">
+///
+/// It is used in conjunction with the {@link Worksheet#addConditionalFormat}
+/// method.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ConditionalFormatError {
+    pub(crate) inner: Arc<Mutex<xlsx::ConditionalFormatError>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ConditionalFormatError::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ConditionalFormatError {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ConditionalFormatError {
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, xlsx::ConditionalFormatError> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Create a new Error/Non-error conditional format struct.
+    ///
+    /// @returns {ConditionalFormatError} - The conditional format object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ConditionalFormatError {
+        ConditionalFormatError {
+            inner: Arc::new(Mutex::new(xlsx::ConditionalFormatError::new())),
+        }
+    }
+
+    /// Invert the conditional format to show non-error values instead of
+    /// errors.
+    ///
+    /// @returns {ConditionalFormatError} - The conditional format object.
+    #[wasm_bindgen(skip_jsdoc)]
+    pub fn invert(&self) -> ConditionalFormatError {
+        impl_method!(self.invert())
+    }
+
+    /// Set the {@link Format} of the conditional format rule.
+    ///
+    /// Not all cell format properties can be set in a conditional format. For
+    /// example the font name and size, or the alignment, are ignored by Excel.
+    ///
+    /// @param {Format} format - The format applied to the cells that match the
+    ///   rule.
+    /// @returns {ConditionalFormatError} - The conditional format object.
+    #[wasm_bindgen(js_name = "setFormat", skip_jsdoc)]
+    pub fn set_format(&self, format: &Format) -> ConditionalFormatError {
+        impl_method!(self.set_format(format.lock().clone()))
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::format::Format;
+
+/// The `ConditionalFormatFormula` struct represents a Formula style conditional
+/// format.
+///
+/// `ConditionalFormatFormula` is used to represent a Formula style conditional
+/// format in Excel. A Formula conditional format highlights formula values in a
+/// range based on a user supplied formula.
+///
+/// <img
+/// src="https://rustxlsxwriter.github.io/images/conditional_format_formula_intro.png">
+///
+/// It is used in conjunction with the {@link Worksheet#addConditionalFormat}
+/// method.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ConditionalFormatFormula {
+    pub(crate) inner: Arc<Mutex<xlsx::ConditionalFormatFormula>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ConditionalFormatFormula::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ConditionalFormatFormula {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ConditionalFormatFormula {
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, xlsx::ConditionalFormatFormula> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Create a new Formula conditional format struct.
+    ///
+    /// @returns {ConditionalFormatFormula} - The conditional format object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ConditionalFormatFormula {
+        ConditionalFormatFormula {
+            inner: Arc::new(Mutex::new(xlsx::ConditionalFormatFormula::new())),
+        }
+    }
+
+    /// Set the formula rule of the conditional format.
+    ///
+    /// The formula is evaluated relative to the top left cell of the range,
+    /// so row-level rules should use a mixed reference like `"=$D2>$E2"` for
+    /// a range starting in row 2.
+    ///
+    /// @param {string} rule - The formula, with or without a leading `=`.
+    /// @returns {ConditionalFormatFormula} - The conditional format object.
+    #[wasm_bindgen(js_name = "setRule", skip_jsdoc)]
+    pub fn set_rule(&self, rule: &str) -> ConditionalFormatFormula {
+        impl_method!(self.set_rule(rule))
+    }
+
+    /// Set the {@link Format} of the conditional format rule.
+    ///
+    /// Not all cell format properties can be set in a conditional format. For
+    /// example the font name and size, or the alignment, are ignored by Excel.
+    ///
+    /// @param {Format} format - The format applied to the cells that match the
+    ///   rule.
+    /// @returns {ConditionalFormatFormula} - The conditional format object.
+    #[wasm_bindgen(js_name = "setFormat", skip_jsdoc)]
+    pub fn set_format(&self, format: &Format) -> ConditionalFormatFormula {
+        impl_method!(self.set_format(format.lock().clone()))
+    }
+}
@@ -0,0 +1,115 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use super::{
+    conditional_format_custom_icon::ConditionalFormatCustomIcon,
+    conditional_format_icon_type::ConditionalFormatIconType,
+};
+
+/// The `ConditionalFormatIconSet` struct represents an Icon Set conditional
+/// format.
+///
+/// `ConditionalFormatIconSet` is used to represent a Cell style conditional
+/// format in Excel. An Icon Set conditional format shows an icon in the cell,
+/// such as a traffic light or an arrow, depending on the value of the cell
+/// relative to the other values in the range.
+///
+/// <img
+/// src="https://rustxlsxwriter.github.io/images/conditional_format_icon_intro.png">
+///
+/// It is used in conjunction with the {@link Worksheet#addConditionalFormat}
+/// method.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ConditionalFormatIconSet {
+    pub(crate) inner: Arc<Mutex<xlsx::ConditionalFormatIconSet>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ConditionalFormatIconSet::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ConditionalFormatIconSet {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ConditionalFormatIconSet {
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, xlsx::ConditionalFormatIconSet> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Create a new Icon Set conditional format struct.
+    ///
+    /// By default the icon set is {@link ConditionalFormatIconType.ThreeTrafficLights}.
+    ///
+    /// @returns {ConditionalFormatIconSet} - The conditional format object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ConditionalFormatIconSet {
+        ConditionalFormatIconSet {
+            // Set the default icon type explicitly so that its default rules
+            // are also set up.
+            inner: Arc::new(Mutex::new(
+                xlsx::ConditionalFormatIconSet::new()
+                    .set_icon_type(xlsx::ConditionalFormatIconType::ThreeTrafficLights),
+            )),
+        }
+    }
+
+    /// Set the icon types such as traffic lights or histograms.
+    ///
+    /// Setting the icon type also resets the icon rules to the Excel defaults
+    /// for that type, so it should be called before
+    /// {@link ConditionalFormatIconSet#setIcons}.
+    ///
+    /// @param {ConditionalFormatIconType} icon_type - The icon type.
+    /// @returns {ConditionalFormatIconSet} - The conditional format object.
+    #[wasm_bindgen(js_name = "setIconType", skip_jsdoc)]
+    pub fn set_icon_type(&self, icon_type: ConditionalFormatIconType) -> ConditionalFormatIconSet {
+        impl_method!(self.set_icon_type(icon_type.into()))
+    }
+
+    /// Reverse the order of the icons in the icon set.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {ConditionalFormatIconSet} - The conditional format object.
+    #[wasm_bindgen(js_name = "reverseIcons", skip_jsdoc)]
+    pub fn reverse_icons(&self, enable: bool) -> ConditionalFormatIconSet {
+        impl_method!(self.reverse_icons(enable))
+    }
+
+    /// Show only the icons and not the data in the cells.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {ConditionalFormatIconSet} - The conditional format object.
+    #[wasm_bindgen(js_name = "showIconsOnly", skip_jsdoc)]
+    pub fn show_icons_only(&self, enable: bool) -> ConditionalFormatIconSet {
+        impl_method!(self.show_icons_only(enable))
+    }
+
+    /// Set the rules and icons of the icon set.
+    ///
+    /// The number of icons (3-5) must match the number of symbols in the icon
+    /// type, otherwise {@link Worksheet#addConditionalFormat} will fail with
+    /// [`XlsxError::ConditionalFormatError`]. The rule of the first icon is
+    /// always ">= 0%", which is the Excel default.
+    ///
+    /// @param {ConditionalFormatCustomIcon[]} icons - The custom icons.
+    /// @returns {ConditionalFormatIconSet} - The conditional format object.
+    #[wasm_bindgen(js_name = "setIcons", skip_jsdoc)]
+    pub fn set_icons(&self, icons: Vec<ConditionalFormatCustomIcon>) -> ConditionalFormatIconSet {
+        let icons: Vec<_> = icons
+            .iter()
+            .map(|icon| icon.inner.lock().unwrap().clone())
+            .collect();
+        impl_method!(self.set_icons(&icons))
+    }
+}
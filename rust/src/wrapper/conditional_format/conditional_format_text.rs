@@ -0,0 +1,79 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::format::Format;
+
+use super::conditional_format_text_rule::ConditionalFormatTextRule;
+
+/// The `ConditionalFormatText` struct represents a Text conditional format.
+///
+/// `ConditionalFormatText` is used to represent a Text style conditional format
+/// in Excel. Text conditional formats use simple matches such as "contains" or
+/// "begins with".
+///
+/// <img
+/// src="https://rustxlsxwriter.github.io/images/conditional_format_text_intro.png">
+///
+/// It is used in conjunction with the {@link Worksheet#addConditionalFormat}
+/// method.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ConditionalFormatText {
+    pub(crate) inner: Arc<Mutex<xlsx::ConditionalFormatText>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ConditionalFormatText::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ConditionalFormatText {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ConditionalFormatText {
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, xlsx::ConditionalFormatText> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Create a new Text conditional format struct.
+    ///
+    /// @returns {ConditionalFormatText} - The conditional format object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ConditionalFormatText {
+        ConditionalFormatText {
+            inner: Arc::new(Mutex::new(xlsx::ConditionalFormatText::new())),
+        }
+    }
+
+    /// Set the rule for the Text conditional format such as "contains" or
+    /// "begins with".
+    ///
+    /// @param {ConditionalFormatTextRule} rule - The rule.
+    /// @returns {ConditionalFormatText} - The conditional format object.
+    #[wasm_bindgen(js_name = "setRule", skip_jsdoc)]
+    pub fn set_rule(&self, rule: &ConditionalFormatTextRule) -> ConditionalFormatText {
+        impl_method!(self.set_rule(rule.inner.clone()))
+    }
+
+    /// Set the {@link Format} of the conditional format rule.
+    ///
+    /// Not all cell format properties can be set in a conditional format. For
+    /// example the font name and size, or the alignment, are ignored by Excel.
+    ///
+    /// @param {Format} format - The format applied to the cells that match the
+    ///   rule.
+    /// @returns {ConditionalFormatText} - The conditional format object.
+    #[wasm_bindgen(js_name = "setFormat", skip_jsdoc)]
+    pub fn set_format(&self, format: &Format) -> ConditionalFormatText {
+        impl_method!(self.set_format(format.lock().clone()))
+    }
+}
@@ -0,0 +1,63 @@
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+/// The `ConditionalFormatTextRule` struct defines the conditional format rule
+/// for {@link ConditionalFormatText}.
+///
+/// The text comparison is case insensitive, as in Excel.
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ConditionalFormatTextRule {
+    pub(crate) inner: xlsx::ConditionalFormatTextRule,
+}
+
+#[wasm_bindgen]
+impl ConditionalFormatTextRule {
+    /// Show the conditional format for text that contains the target
+    /// string.
+    ///
+    /// @param {string} text - The target string.
+    /// @returns {ConditionalFormatTextRule} - The rule object.
+    #[wasm_bindgen(js_name = "contains", skip_jsdoc)]
+    pub fn contains(text: &str) -> ConditionalFormatTextRule {
+        ConditionalFormatTextRule {
+            inner: xlsx::ConditionalFormatTextRule::Contains(text.to_string()),
+        }
+    }
+
+    /// Show the conditional format for text that does not contain the target
+    /// string.
+    ///
+    /// @param {string} text - The target string.
+    /// @returns {ConditionalFormatTextRule} - The rule object.
+    #[wasm_bindgen(js_name = "doesNotContain", skip_jsdoc)]
+    pub fn does_not_contain(text: &str) -> ConditionalFormatTextRule {
+        ConditionalFormatTextRule {
+            inner: xlsx::ConditionalFormatTextRule::DoesNotContain(text.to_string()),
+        }
+    }
+
+    /// Show the conditional format for text that begins with the target
+    /// string.
+    ///
+    /// @param {string} text - The target string.
+    /// @returns {ConditionalFormatTextRule} - The rule object.
+    #[wasm_bindgen(js_name = "beginsWith", skip_jsdoc)]
+    pub fn begins_with(text: &str) -> ConditionalFormatTextRule {
+        ConditionalFormatTextRule {
+            inner: xlsx::ConditionalFormatTextRule::BeginsWith(text.to_string()),
+        }
+    }
+
+    /// Show the conditional format for text that ends with the target
+    /// string.
+    ///
+    /// @param {string} text - The target string.
+    /// @returns {ConditionalFormatTextRule} - The rule object.
+    #[wasm_bindgen(js_name = "endsWith", skip_jsdoc)]
+    pub fn ends_with(text: &str) -> ConditionalFormatTextRule {
+        ConditionalFormatTextRule {
+            inner: xlsx::ConditionalFormatTextRule::EndsWith(text.to_string()),
+        }
+    }
+}
@@ -0,0 +1,87 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::format::Format;
+
+use super::conditional_format_top_rule::ConditionalFormatTopRule;
+
+/// The `ConditionalFormatTop` struct represents a Top/Bottom style conditional
+/// format.
+///
+/// `ConditionalFormatTop` is used to represent a Top or Bottom style
+/// conditional format in Excel. Top conditional formats show the top X values
+/// in a range. The value of the conditional can be a rank, i.e., Top X, or a
+/// percentage, i.e., Top X%.
+///
+/// <img
+/// src="https://rustxlsxwriter.github.io/images/conditional_format_top_intro.png">
+///
+/// It is used in conjunction with the {@link Worksheet#addConditionalFormat}
+/// method.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ConditionalFormatTop {
+    pub(crate) inner: Arc<Mutex<xlsx::ConditionalFormatTop>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ConditionalFormatTop::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ConditionalFormatTop {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ConditionalFormatTop {
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, xlsx::ConditionalFormatTop> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Create a new Top/Bottom conditional format struct.
+    ///
+    /// @returns {ConditionalFormatTop} - The conditional format object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ConditionalFormatTop {
+        ConditionalFormatTop {
+            inner: Arc::new(Mutex::new(xlsx::ConditionalFormatTop::new())),
+        }
+    }
+
+    /// Set the rule for the Top/Bottom conditional format such as "top 10"
+    /// or "bottom 10%".
+    ///
+    /// The rule is created with {@link ConditionalFormatTopRule.top},
+    /// {@link ConditionalFormatTopRule.bottom},
+    /// {@link ConditionalFormatTopRule.topPercent} or
+    /// {@link ConditionalFormatTopRule.bottomPercent} to show the top or bottom
+    /// N ranked values, or the top or bottom N percent of values, in the range.
+    ///
+    /// @param {ConditionalFormatTopRule} rule - The rule.
+    /// @returns {ConditionalFormatTop} - The conditional format object.
+    #[wasm_bindgen(js_name = "setRule", skip_jsdoc)]
+    pub fn set_rule(&self, rule: &ConditionalFormatTopRule) -> ConditionalFormatTop {
+        impl_method!(self.set_rule(rule.inner.clone()))
+    }
+
+    /// Set the {@link Format} of the conditional format rule.
+    ///
+    /// Not all cell format properties can be set in a conditional format. For
+    /// example the font name and size, or the alignment, are ignored by Excel.
+    ///
+    /// @param {Format} format - The format applied to the cells that match the
+    ///   rule.
+    /// @returns {ConditionalFormatTop} - The conditional format object.
+    #[wasm_bindgen(js_name = "setFormat", skip_jsdoc)]
+    pub fn set_format(&self, format: &Format) -> ConditionalFormatTop {
+        impl_method!(self.set_format(format.lock().clone()))
+    }
+}
@@ -0,0 +1,62 @@
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+/// The `ConditionalFormatTopRule` struct defines the conditional format rule
+/// for {@link ConditionalFormatTop}.
+///
+/// The rank or percentage must be in the Excel range 1-1000 for ranks and
+/// 1-100 for percentages.
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ConditionalFormatTopRule {
+    pub(crate) inner: xlsx::ConditionalFormatTopRule,
+}
+
+#[wasm_bindgen]
+impl ConditionalFormatTopRule {
+    /// Show the conditional format for cells that are in the top X.
+    ///
+    /// @param {number} value - The rank. Must be in the Excel range 1-1000.
+    /// @returns {ConditionalFormatTopRule} - The rule object.
+    #[wasm_bindgen(js_name = "top", skip_jsdoc)]
+    pub fn top(value: u16) -> ConditionalFormatTopRule {
+        ConditionalFormatTopRule {
+            inner: xlsx::ConditionalFormatTopRule::Top(value),
+        }
+    }
+
+    /// Show the conditional format for cells that are in the bottom X.
+    ///
+    /// @param {number} value - The rank. Must be in the Excel range 1-1000.
+    /// @returns {ConditionalFormatTopRule} - The rule object.
+    #[wasm_bindgen(js_name = "bottom", skip_jsdoc)]
+    pub fn bottom(value: u16) -> ConditionalFormatTopRule {
+        ConditionalFormatTopRule {
+            inner: xlsx::ConditionalFormatTopRule::Bottom(value),
+        }
+    }
+
+    /// Show the conditional format for cells that are in the top X%.
+    ///
+    /// @param {number} value - The percentage. Must be in the Excel range
+    ///   1-100.
+    /// @returns {ConditionalFormatTopRule} - The rule object.
+    #[wasm_bindgen(js_name = "topPercent", skip_jsdoc)]
+    pub fn top_percent(value: u16) -> ConditionalFormatTopRule {
+        ConditionalFormatTopRule {
+            inner: xlsx::ConditionalFormatTopRule::TopPercent(value),
+        }
+    }
+
+    /// Show the conditional format for cells that are in the bottom X%.
+    ///
+    /// @param {number} value - The percentage. Must be in the Excel range
+    ///   1-100.
+    /// @returns {ConditionalFormatTopRule} - The rule object.
+    #[wasm_bindgen(js_name = "bottomPercent", skip_jsdoc)]
+    pub fn bottom_percent(value: u16) -> ConditionalFormatTopRule {
+        ConditionalFormatTopRule {
+            inner: xlsx::ConditionalFormatTopRule::BottomPercent(value),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+/// The `ConditionalFormatType` enum defines the conditional format types used
+/// for the minimum, midpoint and maximum values of color scales and data bars.
+///
+/// It is used with methods such as {@link ConditionalFormat2ColorScale#setMinimum}
+/// and {@link ConditionalFormat3ColorScale#setMidpoint}.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[wasm_bindgen]
+pub enum ConditionalFormatType {
+    /// Set the color scale/data bar to use the minimum or maximum value in the
+    /// range. This is the default for data bars.
+    Automatic,
+
+    /// Set the color scale/data bar to use the minimum value in the range. This
+    /// is the default for the minimum value in color scales.
+    Lowest,
+
+    /// Set the color scale/data bar to use a number value other than the
+    /// maximum/minimum.
+    Number,
+
+    /// Set the color scale/data bar to use a percentage. This must be in the
+    /// range 0-100.
+    Percent,
+
+    /// Set the color scale/data bar to use a formula value.
+    Formula,
+
+    /// Set the color scale/data bar to use a percentile. This must be in the
+    /// range 0-100.
+    Percentile,
+
+    /// Set the color scale/data bar to use the maximum value in the range. This
+    /// is the default for the maximum value in color scales.
+    Highest,
+}
+
+impl From<ConditionalFormatType> for xlsx::ConditionalFormatType {
+    fn from(rule_type: ConditionalFormatType) -> xlsx::ConditionalFormatType {
+        match rule_type {
+            ConditionalFormatType::Automatic => xlsx::ConditionalFormatType::Automatic,
+            ConditionalFormatType::Lowest => xlsx::ConditionalFormatType::Lowest,
+            ConditionalFormatType::Number => xlsx::ConditionalFormatType::Number,
+            ConditionalFormatType::Percent => xlsx::ConditionalFormatType::Percent,
+            ConditionalFormatType::Formula => xlsx::ConditionalFormatType::Formula,
+            ConditionalFormatType::Percentile => xlsx::ConditionalFormatType::Percentile,
+            ConditionalFormatType::Highest => xlsx::ConditionalFormatType::Highest,
+        }
+    }
+}
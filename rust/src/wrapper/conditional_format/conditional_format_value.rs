@@ -0,0 +1,60 @@
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::error::XlsxError;
+use crate::wrapper::utils;
+
+#[wasm_bindgen(typescript_custom_section)]
+const CONDITIONAL_FORMAT_VALUE: &'static str = r#"
+/**
+ *  Value type that can be used in conditional format rules and in the
+ *  minimum/midpoint/maximum of color scales and data bars.
+ *
+ *  Strings are treated as literal text. Use a {@link Formula} for cell
+ *  references or formulas such as `"=$B$1"`.
+ */
+export type ConditionalFormatValue = string | number | boolean | Date | ExcelDateTime | Formula;
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "ConditionalFormatValue")]
+    pub type JsConditionalFormatValue;
+}
+
+impl TryInto<xlsx::ConditionalFormatValue> for &JsConditionalFormatValue {
+    type Error = XlsxError;
+
+    fn try_into(self) -> Result<xlsx::ConditionalFormatValue, Self::Error> {
+        let jsvalue = JsValue::from(self);
+        let js_type = jsvalue.js_typeof().as_string().unwrap();
+
+        match js_type.as_str() {
+            "string" => Ok(jsvalue.as_string().unwrap().into()),
+            "number" => Ok(jsvalue.as_f64().unwrap().into()),
+            "boolean" => Ok(jsvalue.as_bool().unwrap().into()),
+            "object" if !jsvalue.is_null() => {
+                if let Some(dt) = utils::datetime_of_jsval(jsvalue.clone()) {
+                    Ok(dt.into())
+                } else if let Some(dt) = utils::excel_datetime_of_jsval(&jsvalue) {
+                    let dt = dt.inner.lock().unwrap().clone();
+                    Ok(dt.into())
+                } else if let Some(formula) = utils::formula_of_jsval(&jsvalue) {
+                    let formula = formula.lock().clone();
+                    Ok(formula.into())
+                } else {
+                    let ctor = js_sys::Object::get_prototype_of(&jsvalue)
+                        .constructor()
+                        .name();
+                    Err(XlsxError::Type(format!(
+                        "Cannot use {} (instance of {}) as a conditional format value",
+                        js_type, ctor
+                    )))
+                }
+            }
+            _ => Err(XlsxError::Type(format!(
+                "Cannot use {js_type} as a conditional format value"
+            ))),
+        }
+    }
+}
@@ -0,0 +1,271 @@
+mod conditional_format_2_color_scale;
+mod conditional_format_3_color_scale;
+mod conditional_format_average;
+mod conditional_format_average_rule;
+mod conditional_format_blank;
+mod conditional_format_cell;
+mod conditional_format_cell_rule;
+mod conditional_format_custom_icon;
+mod conditional_format_data_bar;
+mod conditional_format_data_bar_axis_position;
+mod conditional_format_data_bar_direction;
+mod conditional_format_date;
+mod conditional_format_date_rule;
+mod conditional_format_duplicate;
+mod conditional_format_error;
+mod conditional_format_formula;
+mod conditional_format_icon_set;
+mod conditional_format_icon_type;
+mod conditional_format_text;
+mod conditional_format_text_rule;
+mod conditional_format_top;
+mod conditional_format_top_rule;
+mod conditional_format_type;
+mod conditional_format_value;
+
+use std::sync::Arc;
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::error::XlsxError;
+use crate::wrapper::utils;
+
+use conditional_format_2_color_scale::ConditionalFormat2ColorScale;
+use conditional_format_3_color_scale::ConditionalFormat3ColorScale;
+use conditional_format_average::ConditionalFormatAverage;
+use conditional_format_blank::ConditionalFormatBlank;
+use conditional_format_cell::ConditionalFormatCell;
+use conditional_format_data_bar::ConditionalFormatDataBar;
+use conditional_format_date::ConditionalFormatDate;
+use conditional_format_duplicate::ConditionalFormatDuplicate;
+use conditional_format_error::ConditionalFormatError;
+use conditional_format_formula::ConditionalFormatFormula;
+use conditional_format_icon_set::ConditionalFormatIconSet;
+use conditional_format_text::ConditionalFormatText;
+use conditional_format_top::ConditionalFormatTop;
+
+#[wasm_bindgen(typescript_custom_section)]
+const CONDITIONAL_FORMAT: &'static str = r#"
+/**
+ *  Conditional format types that can be added to a worksheet range.
+ *  You can add them via {@link Worksheet#addConditionalFormat}.
+ */
+export type ConditionalFormat =
+  | ConditionalFormat2ColorScale
+  | ConditionalFormat3ColorScale
+  | ConditionalFormatDataBar
+  | ConditionalFormatIconSet
+  | ConditionalFormatCell
+  | ConditionalFormatText
+  | ConditionalFormatDate
+  | ConditionalFormatTop
+  | ConditionalFormatAverage
+  | ConditionalFormatDuplicate
+  | ConditionalFormatBlank
+  | ConditionalFormatError
+  | ConditionalFormatFormula;
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "ConditionalFormat")]
+    pub type JsConditionalFormat;
+}
+
+/// Glue code to make up for the lack of a `rust_xlsxwriter::ConditionalFormat`
+/// trait object on the JS side.
+pub enum ConditionalFormat {
+    TwoColorScale(ConditionalFormat2ColorScale),
+    ThreeColorScale(ConditionalFormat3ColorScale),
+    DataBar(ConditionalFormatDataBar),
+    IconSet(ConditionalFormatIconSet),
+    Cell(ConditionalFormatCell),
+    Text(ConditionalFormatText),
+    Date(ConditionalFormatDate),
+    Top(ConditionalFormatTop),
+    Average(ConditionalFormatAverage),
+    Duplicate(ConditionalFormatDuplicate),
+    Blank(ConditionalFormatBlank),
+    Error(ConditionalFormatError),
+    Formula(ConditionalFormatFormula),
+}
+
+impl TryInto<ConditionalFormat> for &JsConditionalFormat {
+    type Error = XlsxError;
+
+    fn try_into(self) -> Result<ConditionalFormat, Self::Error> {
+        let jsvalue = JsValue::from(self);
+
+        if let Ok(cf) = utils::generic_of_jsval::<ConditionalFormat2ColorScale>(
+            &jsvalue,
+            "ConditionalFormat2ColorScale",
+        ) {
+            Ok(ConditionalFormat::TwoColorScale(cf.clone()))
+        } else if let Ok(cf) = utils::generic_of_jsval::<ConditionalFormat3ColorScale>(
+            &jsvalue,
+            "ConditionalFormat3ColorScale",
+        ) {
+            Ok(ConditionalFormat::ThreeColorScale(cf.clone()))
+        } else if let Ok(cf) = utils::generic_of_jsval::<ConditionalFormatDataBar>(
+            &jsvalue,
+            "ConditionalFormatDataBar",
+        ) {
+            Ok(ConditionalFormat::DataBar(cf.clone()))
+        } else if let Ok(cf) = utils::generic_of_jsval::<ConditionalFormatIconSet>(
+            &jsvalue,
+            "ConditionalFormatIconSet",
+        ) {
+            Ok(ConditionalFormat::IconSet(cf.clone()))
+        } else if let Ok(cf) =
+            utils::generic_of_jsval::<ConditionalFormatCell>(&jsvalue, "ConditionalFormatCell")
+        {
+            Ok(ConditionalFormat::Cell(cf.clone()))
+        } else if let Ok(cf) =
+            utils::generic_of_jsval::<ConditionalFormatText>(&jsvalue, "ConditionalFormatText")
+        {
+            Ok(ConditionalFormat::Text(cf.clone()))
+        } else if let Ok(cf) =
+            utils::generic_of_jsval::<ConditionalFormatDate>(&jsvalue, "ConditionalFormatDate")
+        {
+            Ok(ConditionalFormat::Date(cf.clone()))
+        } else if let Ok(cf) =
+            utils::generic_of_jsval::<ConditionalFormatTop>(&jsvalue, "ConditionalFormatTop")
+        {
+            Ok(ConditionalFormat::Top(cf.clone()))
+        } else if let Ok(cf) = utils::generic_of_jsval::<ConditionalFormatAverage>(
+            &jsvalue,
+            "ConditionalFormatAverage",
+        ) {
+            Ok(ConditionalFormat::Average(cf.clone()))
+        } else if let Ok(cf) = utils::generic_of_jsval::<ConditionalFormatDuplicate>(
+            &jsvalue,
+            "ConditionalFormatDuplicate",
+        ) {
+            Ok(ConditionalFormat::Duplicate(cf.clone()))
+        } else if let Ok(cf) =
+            utils::generic_of_jsval::<ConditionalFormatBlank>(&jsvalue, "ConditionalFormatBlank")
+        {
+            Ok(ConditionalFormat::Blank(cf.clone()))
+        } else if let Ok(cf) =
+            utils::generic_of_jsval::<ConditionalFormatError>(&jsvalue, "ConditionalFormatError")
+        {
+            Ok(ConditionalFormat::Error(cf.clone()))
+        } else if let Ok(cf) = utils::generic_of_jsval::<ConditionalFormatFormula>(
+            &jsvalue,
+            "ConditionalFormatFormula",
+        ) {
+            Ok(ConditionalFormat::Formula(cf.clone()))
+        } else {
+            Err(XlsxError::Type(
+                "Expected an instance of a ConditionalFormat class".to_string(),
+            ))
+        }
+    }
+}
+
+impl ConditionalFormat {
+    pub(crate) fn add_to_worksheet(
+        &self,
+        worksheet: &mut xlsx::Worksheet,
+        first_row: xlsx::RowNum,
+        first_col: xlsx::ColNum,
+        last_row: xlsx::RowNum,
+        last_col: xlsx::ColNum,
+    ) -> Result<(), xlsx::XlsxError> {
+        macro_rules! add {
+            ($cf:expr) => {
+                worksheet.add_conditional_format(
+                    first_row,
+                    first_col,
+                    last_row,
+                    last_col,
+                    &*$cf.lock(),
+                )
+            };
+        }
+
+        match self {
+            ConditionalFormat::TwoColorScale(cf) => add!(cf),
+            ConditionalFormat::ThreeColorScale(cf) => add!(cf),
+            ConditionalFormat::DataBar(cf) => add!(cf),
+            ConditionalFormat::IconSet(cf) => add!(cf),
+            ConditionalFormat::Cell(cf) => add!(cf),
+            ConditionalFormat::Text(cf) => add!(cf),
+            ConditionalFormat::Date(cf) => add!(cf),
+            ConditionalFormat::Top(cf) => add!(cf),
+            ConditionalFormat::Average(cf) => add!(cf),
+            ConditionalFormat::Duplicate(cf) => add!(cf),
+            ConditionalFormat::Blank(cf) => add!(cf),
+            ConditionalFormat::Error(cf) => add!(cf),
+            ConditionalFormat::Formula(cf) => add!(cf),
+        }?;
+        Ok(())
+    }
+}
+
+/// Generate the options that are common to all the conditional format types.
+macro_rules! impl_common_methods {
+    ($($t:ident)*) => ($(
+        #[wasm_bindgen]
+        impl $t {
+            /// Set an additional multi-cell range for the conditional format.
+            ///
+            /// The `setMultiRange()` method is used to extend a conditional
+            /// format over non-contiguous ranges like `"B3:D6 I3:K6 B9:D12
+            /// I9:K12"`.
+            ///
+            /// If the range is invalid then Excel will omit it silently.
+            ///
+            /// @param {string} range - An Excel range like `"B3:D6 I3:K6"` or
+            ///   `"$B$3:$D$6,$I$3:$K$6"`.
+            /// @returns - The conditional format object.
+            #[wasm_bindgen(js_name = "setMultiRange", skip_jsdoc)]
+            pub fn set_multi_range(&self, range: &str) -> $t {
+                let mut lock = self.inner.lock().unwrap();
+                let inner = std::mem::replace(&mut *lock, xlsx::$t::new());
+                *lock = inner.set_multi_range(range);
+                $t {
+                    inner: Arc::clone(&self.inner),
+                }
+            }
+
+            /// Set the "Stop if True" option for the conditional format rule.
+            ///
+            /// The `setStopIfTrue()` method can be used to set the "Stop if
+            /// true" feature of a conditional formatting rule when more than
+            /// one rule is applied to a cell or a range of cells. When this
+            /// parameter is set then subsequent rules are not evaluated if the
+            /// current rule is true.
+            ///
+            /// @param {boolean} enable - Turn the property on/off. It is off by
+            ///   default.
+            /// @returns - The conditional format object.
+            #[wasm_bindgen(js_name = "setStopIfTrue", skip_jsdoc)]
+            pub fn set_stop_if_true(&self, enable: bool) -> $t {
+                let mut lock = self.inner.lock().unwrap();
+                let inner = std::mem::replace(&mut *lock, xlsx::$t::new());
+                *lock = inner.set_stop_if_true(enable);
+                $t {
+                    inner: Arc::clone(&self.inner),
+                }
+            }
+        }
+    )*)
+}
+
+impl_common_methods!(
+    ConditionalFormat2ColorScale
+    ConditionalFormat3ColorScale
+    ConditionalFormatAverage
+    ConditionalFormatBlank
+    ConditionalFormatCell
+    ConditionalFormatDataBar
+    ConditionalFormatDate
+    ConditionalFormatDuplicate
+    ConditionalFormatError
+    ConditionalFormatFormula
+    ConditionalFormatIconSet
+    ConditionalFormatText
+    ConditionalFormatTop
+);
@@ -0,0 +1,27 @@
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+/// The `DataValidationErrorStyle` enum defines the type of error dialog that is
+/// shown when there is and error in a data validation.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[wasm_bindgen]
+pub enum DataValidationErrorStyle {
+    /// Show a "Stop" dialog. This is the default.
+    Stop,
+
+    /// Show a "Warning" dialog.
+    Warning,
+
+    /// Show an "Information" dialog.
+    Information,
+}
+
+impl From<DataValidationErrorStyle> for xlsx::DataValidationErrorStyle {
+    fn from(style: DataValidationErrorStyle) -> xlsx::DataValidationErrorStyle {
+        match style {
+            DataValidationErrorStyle::Stop => xlsx::DataValidationErrorStyle::Stop,
+            DataValidationErrorStyle::Warning => xlsx::DataValidationErrorStyle::Warning,
+            DataValidationErrorStyle::Information => xlsx::DataValidationErrorStyle::Information,
+        }
+    }
+}
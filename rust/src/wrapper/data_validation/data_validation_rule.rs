@@ -0,0 +1,201 @@
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::error::XlsxError;
+use crate::wrapper::WasmResult;
+
+use super::data_validation_value::{DataValidationValue, JsDataValidationValue};
+
+/// The `DataValidationRule` struct defines the data validation rule for
+/// {@link DataValidation}.
+///
+/// The rule operands can be numbers, dates, times or a {@link Formula}. The
+/// `DataValidation.allow*()` method that the rule is passed to determines
+/// which operand types are valid.
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct DataValidationRule {
+    pub(crate) inner: Rule,
+}
+
+/// A copy of `rust_xlsxwriter::DataValidationRule` that holds any JS operand.
+/// It is converted into the concrete rule type required by each
+/// `DataValidation::allow_*()` method via [`DataValidationRule::to_xlsx`].
+#[derive(Clone)]
+pub(crate) enum Rule {
+    EqualTo(DataValidationValue),
+    NotEqualTo(DataValidationValue),
+    GreaterThan(DataValidationValue),
+    GreaterThanOrEqualTo(DataValidationValue),
+    LessThan(DataValidationValue),
+    LessThanOrEqualTo(DataValidationValue),
+    Between(DataValidationValue, DataValidationValue),
+    NotBetween(DataValidationValue, DataValidationValue),
+}
+
+#[wasm_bindgen]
+impl DataValidationRule {
+    /// Restrict cell input to values that are equal to the target value.
+    ///
+    /// @param {DataValidationValue} value - The target value.
+    /// @returns {DataValidationRule} - The rule object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - The value isn't a number, date or formula.
+    #[wasm_bindgen(js_name = "equalTo", skip_jsdoc)]
+    pub fn equal_to(value: &JsDataValidationValue) -> WasmResult<DataValidationRule> {
+        Ok(DataValidationRule {
+            inner: Rule::EqualTo(value.try_into()?),
+        })
+    }
+
+    /// Restrict cell input to values that are not equal to the target value.
+    ///
+    /// @param {DataValidationValue} value - The target value.
+    /// @returns {DataValidationRule} - The rule object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - The value isn't a number, date or formula.
+    #[wasm_bindgen(js_name = "notEqualTo", skip_jsdoc)]
+    pub fn not_equal_to(value: &JsDataValidationValue) -> WasmResult<DataValidationRule> {
+        Ok(DataValidationRule {
+            inner: Rule::NotEqualTo(value.try_into()?),
+        })
+    }
+
+    /// Restrict cell input to values that are greater than the target value.
+    ///
+    /// @param {DataValidationValue} value - The target value.
+    /// @returns {DataValidationRule} - The rule object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - The value isn't a number, date or formula.
+    #[wasm_bindgen(js_name = "greaterThan", skip_jsdoc)]
+    pub fn greater_than(value: &JsDataValidationValue) -> WasmResult<DataValidationRule> {
+        Ok(DataValidationRule {
+            inner: Rule::GreaterThan(value.try_into()?),
+        })
+    }
+
+    /// Restrict cell input to values that are greater than or equal to the
+    /// target value.
+    ///
+    /// @param {DataValidationValue} value - The target value.
+    /// @returns {DataValidationRule} - The rule object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - The value isn't a number, date or formula.
+    #[wasm_bindgen(js_name = "greaterThanOrEqualTo", skip_jsdoc)]
+    pub fn greater_than_or_equal_to(
+        value: &JsDataValidationValue,
+    ) -> WasmResult<DataValidationRule> {
+        Ok(DataValidationRule {
+            inner: Rule::GreaterThanOrEqualTo(value.try_into()?),
+        })
+    }
+
+    /// Restrict cell input to values that are less than the target value.
+    ///
+    /// @param {DataValidationValue} value - The target value.
+    /// @returns {DataValidationRule} - The rule object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - The value isn't a number, date or formula.
+    #[wasm_bindgen(js_name = "lessThan", skip_jsdoc)]
+    pub fn less_than(value: &JsDataValidationValue) -> WasmResult<DataValidationRule> {
+        Ok(DataValidationRule {
+            inner: Rule::LessThan(value.try_into()?),
+        })
+    }
+
+    /// Restrict cell input to values that are less than or equal to the target
+    /// value.
+    ///
+    /// @param {DataValidationValue} value - The target value.
+    /// @returns {DataValidationRule} - The rule object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - The value isn't a number, date or formula.
+    #[wasm_bindgen(js_name = "lessThanOrEqualTo", skip_jsdoc)]
+    pub fn less_than_or_equal_to(value: &JsDataValidationValue) -> WasmResult<DataValidationRule> {
+        Ok(DataValidationRule {
+            inner: Rule::LessThanOrEqualTo(value.try_into()?),
+        })
+    }
+
+    /// Restrict cell input to values that are between the target values.
+    ///
+    /// @param {DataValidationValue} min - The lower target value.
+    /// @param {DataValidationValue} max - The upper target value.
+    /// @returns {DataValidationRule} - The rule object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - A value isn't a number, date or formula.
+    #[wasm_bindgen(js_name = "between", skip_jsdoc)]
+    pub fn between(
+        min: &JsDataValidationValue,
+        max: &JsDataValidationValue,
+    ) -> WasmResult<DataValidationRule> {
+        Ok(DataValidationRule {
+            inner: Rule::Between(min.try_into()?, max.try_into()?),
+        })
+    }
+
+    /// Restrict cell input to values that are not between the target values.
+    ///
+    /// @param {DataValidationValue} min - The lower target value.
+    /// @param {DataValidationValue} max - The upper target value.
+    /// @returns {DataValidationRule} - The rule object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - A value isn't a number, date or formula.
+    #[wasm_bindgen(js_name = "notBetween", skip_jsdoc)]
+    pub fn not_between(
+        min: &JsDataValidationValue,
+        max: &JsDataValidationValue,
+    ) -> WasmResult<DataValidationRule> {
+        Ok(DataValidationRule {
+            inner: Rule::NotBetween(min.try_into()?, max.try_into()?),
+        })
+    }
+}
+
+impl DataValidationRule {
+    /// Convert the rule operands into the type `T` required by one of the
+    /// `rust_xlsxwriter::DataValidation::allow_*()` methods.
+    pub(crate) fn to_xlsx<T>(
+        &self,
+        convert: impl Fn(&DataValidationValue) -> Result<T, XlsxError>,
+    ) -> Result<xlsx::DataValidationRule<T>, XlsxError>
+    where
+        T: xlsx::IntoDataValidationValue,
+    {
+        let rule = match &self.inner {
+            Rule::EqualTo(value) => xlsx::DataValidationRule::EqualTo(convert(value)?),
+            Rule::NotEqualTo(value) => xlsx::DataValidationRule::NotEqualTo(convert(value)?),
+            Rule::GreaterThan(value) => xlsx::DataValidationRule::GreaterThan(convert(value)?),
+            Rule::GreaterThanOrEqualTo(value) => {
+                xlsx::DataValidationRule::GreaterThanOrEqualTo(convert(value)?)
+            }
+            Rule::LessThan(value) => xlsx::DataValidationRule::LessThan(convert(value)?),
+            Rule::LessThanOrEqualTo(value) => {
+                xlsx::DataValidationRule::LessThanOrEqualTo(convert(value)?)
+            }
+            Rule::Between(min, max) => {
+                xlsx::DataValidationRule::Between(convert(min)?, convert(max)?)
+            }
+            Rule::NotBetween(min, max) => {
+                xlsx::DataValidationRule::NotBetween(convert(min)?, convert(max)?)
+            }
+        };
+        Ok(rule)
+    }
+}
@@ -0,0 +1,119 @@
+use chrono::{Datelike, Timelike};
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::error::XlsxError;
+use crate::wrapper::utils;
+
+#[wasm_bindgen(typescript_custom_section)]
+const DATA_VALIDATION_VALUE: &'static str = r#"
+/**
+ *  Value type that can be used as an operand of a {@link DataValidationRule}.
+ *
+ *  Numbers are used for whole number, decimal and text length rules, dates and
+ *  times for date and time rules, and a {@link Formula} for the `*Formula()`
+ *  variants of the rules.
+ */
+export type DataValidationValue = number | Date | ExcelDateTime | Formula;
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "DataValidationValue")]
+    pub type JsDataValidationValue;
+}
+
+#[derive(Clone)]
+pub(crate) enum DataValidationValue {
+    Number(f64),
+    DateTime(xlsx::ExcelDateTime),
+    Formula(xlsx::Formula),
+}
+
+impl TryInto<DataValidationValue> for &JsDataValidationValue {
+    type Error = XlsxError;
+
+    fn try_into(self) -> Result<DataValidationValue, Self::Error> {
+        let jsvalue = JsValue::from(self);
+
+        if let Some(number) = jsvalue.as_f64() {
+            Ok(DataValidationValue::Number(number))
+        } else if let Some(dt) = utils::datetime_of_jsval(jsvalue.clone()) {
+            let dt =
+                xlsx::ExcelDateTime::from_ymd(dt.year() as u16, dt.month() as u8, dt.day() as u8)?
+                    .and_hms_milli(
+                        dt.hour() as u16,
+                        dt.minute() as u8,
+                        dt.second() as u8,
+                        (dt.nanosecond() / 1_000_000) as u16,
+                    )?;
+            Ok(DataValidationValue::DateTime(dt))
+        } else if let Some(dt) = utils::excel_datetime_of_jsval(&jsvalue) {
+            let dt = dt.inner.lock().unwrap().clone();
+            Ok(DataValidationValue::DateTime(dt))
+        } else if let Some(formula) = utils::formula_of_jsval(&jsvalue) {
+            Ok(DataValidationValue::Formula(formula.lock().clone()))
+        } else {
+            Err(XlsxError::Type(
+                "Expected a number, Date, ExcelDateTime or Formula as a data validation value"
+                    .to_string(),
+            ))
+        }
+    }
+}
+
+impl DataValidationValue {
+    pub(crate) fn to_i32(&self) -> Result<i32, XlsxError> {
+        match self {
+            DataValidationValue::Number(n)
+                if n.fract() == 0.0 && (i32::MIN as f64..=i32::MAX as f64).contains(n) =>
+            {
+                Ok(*n as i32)
+            }
+            _ => Err(XlsxError::Type(
+                "Expected an integer as a whole number data validation value".to_string(),
+            )),
+        }
+    }
+
+    pub(crate) fn to_u32(&self) -> Result<u32, XlsxError> {
+        match self {
+            DataValidationValue::Number(n)
+                if n.fract() == 0.0 && (0.0..=u32::MAX as f64).contains(n) =>
+            {
+                Ok(*n as u32)
+            }
+            _ => Err(XlsxError::Type(
+                "Expected a non-negative integer as a text length data validation value"
+                    .to_string(),
+            )),
+        }
+    }
+
+    pub(crate) fn to_f64(&self) -> Result<f64, XlsxError> {
+        match self {
+            DataValidationValue::Number(n) => Ok(*n),
+            _ => Err(XlsxError::Type(
+                "Expected a number as a decimal data validation value".to_string(),
+            )),
+        }
+    }
+
+    pub(crate) fn to_datetime(&self) -> Result<xlsx::ExcelDateTime, XlsxError> {
+        match self {
+            DataValidationValue::DateTime(dt) => Ok(dt.clone()),
+            _ => Err(XlsxError::Type(
+                "Expected a Date or ExcelDateTime as a date/time data validation value".to_string(),
+            )),
+        }
+    }
+
+    pub(crate) fn to_formula(&self) -> Result<xlsx::Formula, XlsxError> {
+        match self {
+            DataValidationValue::Formula(formula) => Ok(formula.clone()),
+            _ => Err(XlsxError::Type(
+                "Expected a Formula as a data validation formula value".to_string(),
+            )),
+        }
+    }
+}
@@ -0,0 +1,435 @@
+mod data_validation_error_style;
+mod data_validation_rule;
+mod data_validation_value;
+
+use std::sync::{Arc, Mutex};
+
+use data_validation_error_style::DataValidationErrorStyle;
+use data_validation_rule::DataValidationRule;
+use data_validation_value::DataValidationValue;
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::formula::Formula;
+use crate::wrapper::WasmResult;
+
+/// The `DataValidation` struct represents a data validation in Excel.
+///
+/// Data validation is a feature of Excel that allows you to restrict the data
+/// that a user enters in a cell and to display associated help and warning
+/// messages. It also allows you to restrict input to values in a dropdown list.
+///
+/// <img
+/// src="https://rustxlsxwriter.github.io/images/data_validation_intro1.png">
+///
+/// It is used in conjunction with the {@link Worksheet#addDataValidation}
+/// method.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct DataValidation {
+    pub(crate) inner: Arc<Mutex<xlsx::DataValidation>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::DataValidation::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        DataValidation {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+// Variant of `impl_method!` for the methods that return a `Result`. The data
+// validation is left unchanged if there is an error.
+macro_rules! impl_try_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let inner = lock.clone().$method($($arg),*)?;
+        let _ = std::mem::replace(&mut *lock, inner);
+        DataValidation {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl DataValidation {
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, xlsx::DataValidation> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Create a new cell Data Validation struct.
+    ///
+    /// The default type of a new data validation is equivalent to Excel's "Any"
+    /// data validation. Refer to the `allow*()` methods below to constrain the
+    /// data validation to defined types and to apply rules.
+    ///
+    /// @returns {DataValidation} - The data validation object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> DataValidation {
+        DataValidation {
+            inner: Arc::new(Mutex::new(xlsx::DataValidation::new())),
+        }
+    }
+
+    /// Set a data validation to limit input to integers using defined rules.
+    ///
+    /// Excel refers to this data validation type as "Whole number". The rule
+    /// operands must be integers in the `i32` range.
+    ///
+    /// @param {DataValidationRule} rule - The rule, such as "between" or "less
+    ///   than".
+    /// @returns {DataValidation} - The data validation object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - A rule operand isn't an integer.
+    #[wasm_bindgen(js_name = "allowWholeNumber", skip_jsdoc)]
+    pub fn allow_whole_number(&self, rule: &DataValidationRule) -> WasmResult<DataValidation> {
+        let rule = rule.to_xlsx(DataValidationValue::to_i32)?;
+        Ok(impl_method!(self.allow_whole_number(rule)))
+    }
+
+    /// Set a data validation to limit input to integers using defined rules
+    /// and a cell reference.
+    ///
+    /// @param {DataValidationRule} rule - The rule, with {@link Formula}
+    ///   operands such as `"=B1"`.
+    /// @returns {DataValidation} - The data validation object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - A rule operand isn't a formula.
+    #[wasm_bindgen(js_name = "allowWholeNumberFormula", skip_jsdoc)]
+    pub fn allow_whole_number_formula(
+        &self,
+        rule: &DataValidationRule,
+    ) -> WasmResult<DataValidation> {
+        let rule = rule.to_xlsx(DataValidationValue::to_formula)?;
+        Ok(impl_method!(self.allow_whole_number_formula(rule)))
+    }
+
+    /// Set a data validation to limit input to floating point numbers using
+    /// defined rules.
+    ///
+    /// Excel refers to this data validation type as "Decimal".
+    ///
+    /// @param {DataValidationRule} rule - The rule, such as "between" or "less
+    ///   than".
+    /// @returns {DataValidation} - The data validation object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - A rule operand isn't a number.
+    #[wasm_bindgen(js_name = "allowDecimalNumber", skip_jsdoc)]
+    pub fn allow_decimal_number(&self, rule: &DataValidationRule) -> WasmResult<DataValidation> {
+        let rule = rule.to_xlsx(DataValidationValue::to_f64)?;
+        Ok(impl_method!(self.allow_decimal_number(rule)))
+    }
+
+    /// Set a data validation to limit input to floating point numbers using
+    /// defined rules and a cell reference.
+    ///
+    /// @param {DataValidationRule} rule - The rule, with {@link Formula}
+    ///   operands such as `"=B1"`.
+    /// @returns {DataValidation} - The data validation object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - A rule operand isn't a formula.
+    #[wasm_bindgen(js_name = "allowDecimalNumberFormula", skip_jsdoc)]
+    pub fn allow_decimal_number_formula(
+        &self,
+        rule: &DataValidationRule,
+    ) -> WasmResult<DataValidation> {
+        let rule = rule.to_xlsx(DataValidationValue::to_formula)?;
+        Ok(impl_method!(self.allow_decimal_number_formula(rule)))
+    }
+
+    /// Set a data validation to restrict cell input to a selection of strings
+    /// via a dropdown menu.
+    ///
+    /// The total length of the list, including the separating commas, must
+    /// not exceed Excel's limit of 255 characters. Use
+    /// {@link DataValidation#allowListFormula} with a cell range for longer
+    /// lists.
+    ///
+    /// @param {string[]} list - The strings shown in the dropdown.
+    /// @returns {DataValidation} - The data validation object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::DataValidationError`] - The length of the list, including
+    ///   commas, exceeds Excel's limit of 255 characters.
+    #[wasm_bindgen(js_name = "allowListStrings", skip_jsdoc)]
+    pub fn allow_list_strings(&self, list: Vec<String>) -> WasmResult<DataValidation> {
+        Ok(impl_try_method!(self.allow_list_strings(&list)))
+    }
+
+    /// Set a data validation to restrict cell input to a selection of strings
+    /// via a dropdown menu and a cell range reference.
+    ///
+    /// @param {Formula} list - A cell range reference such as `"=B1:B9"`,
+    ///   `"=$B$1:$B$9"` or `"=Sheet2!B1:B9"`.
+    /// @returns {DataValidation} - The data validation object.
+    #[wasm_bindgen(js_name = "allowListFormula", skip_jsdoc)]
+    pub fn allow_list_formula(&self, list: &Formula) -> DataValidation {
+        impl_method!(self.allow_list_formula(list.lock().clone()))
+    }
+
+    /// Set a data validation to limit input to dates using defined rules.
+    ///
+    /// @param {DataValidationRule} rule - The rule, with `Date` or
+    ///   {@link ExcelDateTime} operands.
+    /// @returns {DataValidation} - The data validation object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - A rule operand isn't a date.
+    #[wasm_bindgen(js_name = "allowDate", skip_jsdoc)]
+    pub fn allow_date(&self, rule: &DataValidationRule) -> WasmResult<DataValidation> {
+        let rule = rule.to_xlsx(DataValidationValue::to_datetime)?;
+        Ok(impl_method!(self.allow_date(rule)))
+    }
+
+    /// Set a data validation to limit input to dates using defined rules and a
+    /// cell reference.
+    ///
+    /// @param {DataValidationRule} rule - The rule, with {@link Formula}
+    ///   operands such as `"=B1"`.
+    /// @returns {DataValidation} - The data validation object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - A rule operand isn't a formula.
+    #[wasm_bindgen(js_name = "allowDateFormula", skip_jsdoc)]
+    pub fn allow_date_formula(&self, rule: &DataValidationRule) -> WasmResult<DataValidation> {
+        let rule = rule.to_xlsx(DataValidationValue::to_formula)?;
+        Ok(impl_method!(self.allow_date_formula(rule)))
+    }
+
+    /// Set a data validation to limit input to times using defined rules.
+    ///
+    /// @param {DataValidationRule} rule - The rule, with time operands such as
+    ///   `ExcelDateTime.fromHMS(12, 0, 0)`.
+    /// @returns {DataValidation} - The data validation object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - A rule operand isn't a time.
+    #[wasm_bindgen(js_name = "allowTime", skip_jsdoc)]
+    pub fn allow_time(&self, rule: &DataValidationRule) -> WasmResult<DataValidation> {
+        let rule = rule.to_xlsx(DataValidationValue::to_datetime)?;
+        Ok(impl_method!(self.allow_time(rule)))
+    }
+
+    /// Set a data validation to limit input to times using defined rules and a
+    /// cell reference.
+    ///
+    /// @param {DataValidationRule} rule - The rule, with {@link Formula}
+    ///   operands such as `"=B1"`.
+    /// @returns {DataValidation} - The data validation object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - A rule operand isn't a formula.
+    #[wasm_bindgen(js_name = "allowTimeFormula", skip_jsdoc)]
+    pub fn allow_time_formula(&self, rule: &DataValidationRule) -> WasmResult<DataValidation> {
+        let rule = rule.to_xlsx(DataValidationValue::to_formula)?;
+        Ok(impl_method!(self.allow_time_formula(rule)))
+    }
+
+    /// Set a data validation to restrict input to strings of a certain length
+    /// using defined rules.
+    ///
+    /// @param {DataValidationRule} rule - The rule, with non-negative integer
+    ///   operands.
+    /// @returns {DataValidation} - The data validation object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - A rule operand isn't a non-negative integer.
+    #[wasm_bindgen(js_name = "allowTextLength", skip_jsdoc)]
+    pub fn allow_text_length(&self, rule: &DataValidationRule) -> WasmResult<DataValidation> {
+        let rule = rule.to_xlsx(DataValidationValue::to_u32)?;
+        Ok(impl_method!(self.allow_text_length(rule)))
+    }
+
+    /// Set a data validation to restrict input to strings of a certain length
+    /// using defined rules and a cell reference.
+    ///
+    /// @param {DataValidationRule} rule - The rule, with {@link Formula}
+    ///   operands such as `"=B1"`.
+    /// @returns {DataValidation} - The data validation object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - A rule operand isn't a formula.
+    #[wasm_bindgen(js_name = "allowTextLengthFormula", skip_jsdoc)]
+    pub fn allow_text_length_formula(
+        &self,
+        rule: &DataValidationRule,
+    ) -> WasmResult<DataValidation> {
+        let rule = rule.to_xlsx(DataValidationValue::to_formula)?;
+        Ok(impl_method!(self.allow_text_length_formula(rule)))
+    }
+
+    /// Set a data validation to restrict input based on a custom formula.
+    ///
+    /// The formula should evaluate to `TRUE` for valid input, for example
+    /// `"=AND(ISTEXT(B3), LEN(B3) <= 6)"`.
+    ///
+    /// @param {Formula} rule - The custom formula.
+    /// @returns {DataValidation} - The data validation object.
+    #[wasm_bindgen(js_name = "allowCustom", skip_jsdoc)]
+    pub fn allow_custom(&self, rule: &Formula) -> DataValidation {
+        impl_method!(self.allow_custom(rule.lock().clone()))
+    }
+
+    /// Set a data validation to allow any input data.
+    ///
+    /// This is the default for a new data validation and is mainly useful to
+    /// show an input message without restricting the input.
+    ///
+    /// @returns {DataValidation} - The data validation object.
+    #[wasm_bindgen(js_name = "allowAnyValue", skip_jsdoc)]
+    pub fn allow_any_value(&self) -> DataValidation {
+        impl_method!(self.allow_any_value())
+    }
+
+    /// Ignore blank cells in the data validation range.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is on by default.
+    /// @returns {DataValidation} - The data validation object.
+    #[wasm_bindgen(js_name = "ignoreBlank", skip_jsdoc)]
+    pub fn ignore_blank(&self, enable: bool) -> DataValidation {
+        impl_method!(self.ignore_blank(enable))
+    }
+
+    /// Show the dropdown for list data validations.
+    ///
+    /// If this option is turned off the data validation will restrict input to
+    /// the specified list values but it won't display a visual indicator of
+    /// what those values are.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is on by default.
+    /// @returns {DataValidation} - The data validation object.
+    #[wasm_bindgen(js_name = "showDropdown", skip_jsdoc)]
+    pub fn show_dropdown(&self, enable: bool) -> DataValidation {
+        impl_method!(self.show_dropdown(enable))
+    }
+
+    /// Show an input message when a data validation cell is entered.
+    ///
+    /// The option only has an effect if there is an input message.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is on by default.
+    /// @returns {DataValidation} - The data validation object.
+    #[wasm_bindgen(js_name = "showInputMessage", skip_jsdoc)]
+    pub fn show_input_message(&self, enable: bool) -> DataValidation {
+        impl_method!(self.show_input_message(enable))
+    }
+
+    /// Set the title for the input message when a data validation cell is
+    /// entered.
+    ///
+    /// The title is only visible if there is also an input message.
+    ///
+    /// @param {string} text - The title. The Excel limit is 32 characters.
+    /// @returns {DataValidation} - The data validation object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::DataValidationError`] - The length of the title exceeds
+    ///   Excel's limit of 32 characters.
+    #[wasm_bindgen(js_name = "setInputTitle", skip_jsdoc)]
+    pub fn set_input_title(&self, text: &str) -> WasmResult<DataValidation> {
+        Ok(impl_try_method!(self.set_input_title(text)))
+    }
+
+    /// Set the input message when a data validation cell is entered.
+    ///
+    /// @param {string} text - The message. Newlines can be added with `"\n"`.
+    ///   The Excel limit is 255 characters.
+    /// @returns {DataValidation} - The data validation object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::DataValidationError`] - The length of the message exceeds
+    ///   Excel's limit of 255 characters.
+    #[wasm_bindgen(js_name = "setInputMessage", skip_jsdoc)]
+    pub fn set_input_message(&self, text: &str) -> WasmResult<DataValidation> {
+        Ok(impl_try_method!(self.set_input_message(text)))
+    }
+
+    /// Show an error message when there is a validation error.
+    ///
+    /// If this option is turned off then any data can be entered in a cell and
+    /// an error message will not be raised.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is on by default.
+    /// @returns {DataValidation} - The data validation object.
+    #[wasm_bindgen(js_name = "showErrorMessage", skip_jsdoc)]
+    pub fn show_error_message(&self, enable: bool) -> DataValidation {
+        impl_method!(self.show_error_message(enable))
+    }
+
+    /// Set the title for the error message when there is a validation error.
+    ///
+    /// @param {string} text - The title. The Excel limit is 32 characters.
+    /// @returns {DataValidation} - The data validation object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::DataValidationError`] - The length of the title exceeds
+    ///   Excel's limit of 32 characters.
+    #[wasm_bindgen(js_name = "setErrorTitle", skip_jsdoc)]
+    pub fn set_error_title(&self, text: &str) -> WasmResult<DataValidation> {
+        Ok(impl_try_method!(self.set_error_title(text)))
+    }
+
+    /// Set the error message when there is a validation error.
+    ///
+    /// @param {string} text - The message. Newlines can be added with `"\n"`.
+    ///   The Excel limit is 255 characters.
+    /// @returns {DataValidation} - The data validation object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::DataValidationError`] - The length of the message exceeds
+    ///   Excel's limit of 255 characters.
+    #[wasm_bindgen(js_name = "setErrorMessage", skip_jsdoc)]
+    pub fn set_error_message(&self, text: &str) -> WasmResult<DataValidation> {
+        Ok(impl_try_method!(self.set_error_message(text)))
+    }
+
+    /// Set the style of the error dialog type for a data validation.
+    ///
+    /// Set the error dialog to be either "Stop" (the default), "Warning" or
+    /// "Information". This option only has an effect on Windows.
+    ///
+    /// @param {DataValidationErrorStyle} error_style - The error dialog style.
+    /// @returns {DataValidation} - The data validation object.
+    #[wasm_bindgen(js_name = "setErrorStyle", skip_jsdoc)]
+    pub fn set_error_style(&self, error_style: DataValidationErrorStyle) -> DataValidation {
+        impl_method!(self.set_error_style(error_style.into()))
+    }
+
+    /// Set an additional multi-cell range for the data validation.
+    ///
+    /// The `setMultiRange()` method is used to extend a data validation over
+    /// non-contiguous ranges like `"B3 I3 B9:D12 I9:K12"`.
+    ///
+    /// @param {string} range - An Excel range like `"B3:D6 I3:K6"` or
+    ///   `"$B$3:$D$6,$I$3:$K$6"`.
+    /// @returns {DataValidation} - The data validation object.
+    #[wasm_bindgen(js_name = "setMultiRange", skip_jsdoc)]
+    pub fn set_multi_range(&self, range: &str) -> DataValidation {
+        impl_method!(self.set_multi_range(range))
+    }
+}
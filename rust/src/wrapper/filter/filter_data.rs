@@ -0,0 +1,57 @@
+use rust_xlsxwriter::{self as xlsx, IntoFilterData};
+use wasm_bindgen::prelude::*;
+
+use crate::error::XlsxError;
+
+#[wasm_bindgen(typescript_custom_section)]
+const FILTER_DATA: &'static str = r#"
+/**
+ *  Value type that can be used in the conditions of a {@link FilterCondition}.
+ *
+ *  Currently only Excel's string and number filter values are supported.
+ */
+export type FilterData = string | number;
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(typescript_type = "FilterData")]
+    pub type JsFilterData;
+}
+
+pub(crate) enum FilterData {
+    String(String),
+    Number(f64),
+}
+
+impl TryInto<FilterData> for &JsFilterData {
+    type Error = XlsxError;
+
+    fn try_into(self) -> Result<FilterData, Self::Error> {
+        let jsvalue = JsValue::from(self);
+
+        if let Some(string) = jsvalue.as_string() {
+            Ok(FilterData::String(string))
+        } else if let Some(number) = jsvalue.as_f64() {
+            Ok(FilterData::Number(number))
+        } else {
+            let js_type = jsvalue.js_typeof().as_string().unwrap();
+            Err(XlsxError::Type(format!(
+                "Cannot use {js_type} as a filter value"
+            )))
+        }
+    }
+}
+
+impl IntoFilterData for FilterData {
+    fn new_filter_data(&self, criteria: xlsx::FilterCriteria) -> xlsx::FilterData {
+        match self {
+            FilterData::String(string) => {
+                xlsx::FilterData::new_string_and_criteria(string, criteria)
+            }
+            FilterData::Number(number) => {
+                xlsx::FilterData::new_number_and_criteria(*number, criteria)
+            }
+        }
+    }
+}
@@ -0,0 +1,135 @@
+mod filter_criteria;
+mod filter_data;
+
+use std::sync::{Arc, Mutex};
+
+use filter_criteria::FilterCriteria;
+use filter_data::{FilterData, JsFilterData};
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::WasmResult;
+
+/// The `FilterCondition` struct is used to define autofilter rules.
+///
+/// Autofilter rules are associated with ranges created using
+/// {@link Worksheet#autofilter} and are applied to a column with
+/// {@link Worksheet#filterColumn}.
+///
+/// Excel supports two main types of filter conditions. The first, and most
+/// common, is a list filter where the user selects the items to filter from a
+/// list of all the values in the column range:
+///
+/// <img src="https://rustxlsxwriter.github.io/images/autofilter_list.png">
+///
+/// The other main type of filter is a custom filter where the user can specify
+/// 1 or 2 conditions like ">= 4000" and "<= 6000":
+///
+/// <img src="https://rustxlsxwriter.github.io/images/autofilter_custom.png">
+///
+/// In Excel these are mutually exclusive and you will need to choose one or the
+/// other via the {@link FilterCondition#addListFilter} and
+/// {@link FilterCondition#addCustomFilter} methods.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct FilterCondition {
+    pub(crate) inner: Arc<Mutex<xlsx::FilterCondition>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::FilterCondition::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        FilterCondition {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl FilterCondition {
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, xlsx::FilterCondition> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Create a new `FilterCondition` struct to define autofilter rules
+    /// associated with an {@link Worksheet#autofilter} range and the
+    /// {@link Worksheet#filterColumn} method.
+    ///
+    /// @returns {FilterCondition} - The filter condition object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> FilterCondition {
+        FilterCondition {
+            inner: Arc::new(Mutex::new(xlsx::FilterCondition::new())),
+        }
+    }
+
+    /// Add a list filter condition.
+    ///
+    /// Add a list style filter condition, i.e. the items to show from the list
+    /// of all the values in the column range. This method can be called
+    /// several times to add more items to the list.
+    ///
+    /// @param {FilterData} value - A string or number value to filter on.
+    /// @returns {FilterCondition} - The filter condition object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - The value isn't a string or a number.
+    #[wasm_bindgen(js_name = "addListFilter", skip_jsdoc)]
+    pub fn add_list_filter(&self, value: &JsFilterData) -> WasmResult<FilterCondition> {
+        let value: FilterData = value.try_into()?;
+        Ok(impl_method!(self.add_list_filter(value)))
+    }
+
+    /// Add a list filter condition to match blank cells.
+    ///
+    /// This can be combined with {@link FilterCondition#addListFilter} to
+    /// match blanks as well as other values.
+    ///
+    /// @returns {FilterCondition} - The filter condition object.
+    #[wasm_bindgen(js_name = "addListBlanksFilter", skip_jsdoc)]
+    pub fn add_list_blanks_filter(&self) -> FilterCondition {
+        impl_method!(self.add_list_blanks_filter())
+    }
+
+    /// Add a custom filter condition.
+    ///
+    /// Add a custom filter condition like ">= 4000". Excel allows up to two
+    /// custom conditions per column. The conditions are combined with a logical
+    /// "and" unless {@link FilterCondition#addCustomBooleanOr} is used. Any
+    /// further conditions are ignored.
+    ///
+    /// @param {FilterCriteria} criteria - The criteria/operator to use in the
+    ///   filter.
+    /// @param {FilterData} value - A string or number value to filter on.
+    /// @returns {FilterCondition} - The filter condition object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::Type`] - The value isn't a string or a number.
+    #[wasm_bindgen(js_name = "addCustomFilter", skip_jsdoc)]
+    pub fn add_custom_filter(
+        &self,
+        criteria: FilterCriteria,
+        value: &JsFilterData,
+    ) -> WasmResult<FilterCondition> {
+        let value: FilterData = value.try_into()?;
+        Ok(impl_method!(self.add_custom_filter(criteria.into(), value)))
+    }
+
+    /// Add an "or" logical condition for two custom filters.
+    ///
+    /// When two custom conditions are specified the logical operator defaults
+    /// to "and", as in Excel. This method changes it to "or".
+    ///
+    /// @returns {FilterCondition} - The filter condition object.
+    #[wasm_bindgen(js_name = "addCustomBooleanOr", skip_jsdoc)]
+    pub fn add_custom_boolean_or(&self) -> FilterCondition {
+        impl_method!(self.add_custom_boolean_or())
+    }
+}
@@ -679,6 +679,20 @@ impl Format {
     pub fn set_quote_prefix(&self) -> Format {
         impl_method!(self.set_quote_prefix());
     }
+
+    /// Set the Format property to show a checkbox in a cell.
+    ///
+    /// This format property can be used with a cell that contains a boolean
+    /// value to display it as a checkbox, for example with
+    /// {@link Worksheet#writeBooleanWithFormat}. This property isn't required
+    /// very often and it is generally easier to create a checkbox using the
+    /// {@link Worksheet#insertCheckbox} method.
+    ///
+    /// @return {Format} - The Format instance.
+    #[wasm_bindgen(js_name = "setCheckbox", skip_jsdoc)]
+    pub fn set_checkbox(&self) -> Format {
+        impl_method!(self.set_checkbox());
+    }
 }
 
 /// The `FormatAlign` enum defines the vertical and horizontal alignment properties
@@ -0,0 +1,60 @@
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+/// The `IgnoreError` enum defines the Excel cell error types that can be
+/// ignored.
+///
+/// It is used with the {@link Worksheet#ignoreError} and
+/// {@link Worksheet#ignoreErrorRange} methods to turn off the green triangle
+/// warnings that Excel displays in cells.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[wasm_bindgen]
+pub enum IgnoreError {
+    /// Ignore errors/warnings for numbers stored as text.
+    NumberStoredAsText,
+
+    /// Ignore errors/warnings for formula evaluation errors (such as divide by
+    /// zero).
+    FormulaError,
+
+    /// Ignore errors/warnings for formulas that differ from surrounding
+    /// formulas.
+    FormulaDiffers,
+
+    /// Ignore errors/warnings for formulas that refer to empty cells.
+    FormulaRefersToEmptyCells,
+
+    /// Ignore errors/warnings for formulas that omit cells in a range.
+    FormulaOmitsCells,
+
+    /// Ignore errors/warnings for cells in a table that do not comply with
+    /// applicable data validation rules.
+    DataValidationError,
+
+    /// Ignore errors/warnings for formulas that contain a two digit text
+    /// representation of a year.
+    TwoDigitTextYear,
+
+    /// Ignore errors/warnings for unlocked cells that contain formulas.
+    UnlockedCellsWithFormula,
+
+    /// Ignore errors/warnings for cell formulas that differ from the column
+    /// formula.
+    InconsistentColumnFormula,
+}
+
+impl From<IgnoreError> for xlsx::IgnoreError {
+    fn from(error_type: IgnoreError) -> xlsx::IgnoreError {
+        match error_type {
+            IgnoreError::NumberStoredAsText => xlsx::IgnoreError::NumberStoredAsText,
+            IgnoreError::FormulaError => xlsx::IgnoreError::FormulaError,
+            IgnoreError::FormulaDiffers => xlsx::IgnoreError::FormulaDiffers,
+            IgnoreError::FormulaRefersToEmptyCells => xlsx::IgnoreError::FormulaRefersToEmptyCells,
+            IgnoreError::FormulaOmitsCells => xlsx::IgnoreError::FormulaOmitsCells,
+            IgnoreError::DataValidationError => xlsx::IgnoreError::DataValidationError,
+            IgnoreError::TwoDigitTextYear => xlsx::IgnoreError::TwoDigitTextYear,
+            IgnoreError::UnlockedCellsWithFormula => xlsx::IgnoreError::UnlockedCellsWithFormula,
+            IgnoreError::InconsistentColumnFormula => xlsx::IgnoreError::InconsistentColumnFormula,
+        }
+    }
+}
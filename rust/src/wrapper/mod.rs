@@ -1,15 +1,23 @@
+mod button;
 mod chart;
 mod color;
+mod conditional_format;
+mod data_validation;
 mod datetime;
 mod doc_properties;
 mod excel_data;
+mod filter;
 mod format;
 mod formula;
 mod header_image_position;
+mod ignore_error;
 mod image;
 mod note;
 mod object_movement;
+mod protection_options;
 mod rich_string;
+mod shape;
+mod sparkline;
 mod table;
 mod url;
 mod utils;
@@ -0,0 +1,145 @@
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+/// The `ProtectionOptions` struct is used to set protected elements in a
+/// worksheet.
+///
+/// You can specify which worksheet elements should be protected or unprotected
+/// via the `ProtectionOptions` properties. The corresponding Excel options with
+/// their default states are shown below:
+///
+/// <img
+/// src="https://rustxlsxwriter.github.io/images/worksheet_protect_with_options1.png">
+///
+/// It is used in conjunction with the {@link Worksheet#protectWithOptions}
+/// method.
+///
+/// TODO: example omitted
+#[derive(Clone, Copy)]
+#[wasm_bindgen]
+pub struct ProtectionOptions {
+    /// When `true` (the default), the user can select locked cells in a
+    /// protected worksheet.
+    #[wasm_bindgen(js_name = "selectLockedCells")]
+    pub select_locked_cells: bool,
+
+    /// When `true` (the default), the user can select unlocked cells in a
+    /// protected worksheet.
+    #[wasm_bindgen(js_name = "selectUnlockedCells")]
+    pub select_unlocked_cells: bool,
+
+    /// When `false` (the default), the user cannot format cells in a protected
+    /// worksheet.
+    #[wasm_bindgen(js_name = "formatCells")]
+    pub format_cells: bool,
+
+    /// When `false` (the default), the user cannot format columns in a
+    /// protected worksheet.
+    #[wasm_bindgen(js_name = "formatColumns")]
+    pub format_columns: bool,
+
+    /// When `false` (the default), the user cannot format rows in a protected
+    /// worksheet.
+    #[wasm_bindgen(js_name = "formatRows")]
+    pub format_rows: bool,
+
+    /// When `false` (the default), the user cannot insert new columns in a
+    /// protected worksheet.
+    #[wasm_bindgen(js_name = "insertColumns")]
+    pub insert_columns: bool,
+
+    /// When `false` (the default), the user cannot insert new rows in a
+    /// protected worksheet.
+    #[wasm_bindgen(js_name = "insertRows")]
+    pub insert_rows: bool,
+
+    /// When `false` (the default), the user cannot insert hyperlinks/URLs in a
+    /// protected worksheet.
+    #[wasm_bindgen(js_name = "insertLinks")]
+    pub insert_links: bool,
+
+    /// When `false` (the default), the user cannot delete columns in a
+    /// protected worksheet.
+    #[wasm_bindgen(js_name = "deleteColumns")]
+    pub delete_columns: bool,
+
+    /// When `false` (the default), the user cannot delete rows in a protected
+    /// worksheet.
+    #[wasm_bindgen(js_name = "deleteRows")]
+    pub delete_rows: bool,
+
+    /// When `false` (the default), the user cannot sort data in a protected
+    /// worksheet.
+    pub sort: bool,
+
+    /// When `false` (the default), the user cannot use autofilters in a
+    /// protected worksheet.
+    #[wasm_bindgen(js_name = "useAutofilter")]
+    pub use_autofilter: bool,
+
+    /// When `false` (the default), the user cannot use pivot tables or pivot
+    /// charts in a protected worksheet.
+    #[wasm_bindgen(js_name = "usePivotTables")]
+    pub use_pivot_tables: bool,
+
+    /// When `false` (the default), the user cannot edit scenarios in a
+    /// protected worksheet.
+    #[wasm_bindgen(js_name = "editScenarios")]
+    pub edit_scenarios: bool,
+
+    /// When `false` (the default), the user cannot edit objects such as images,
+    /// charts, or textboxes in a protected worksheet.
+    #[wasm_bindgen(js_name = "editObjects")]
+    pub edit_objects: bool,
+}
+
+#[wasm_bindgen]
+impl ProtectionOptions {
+    /// Create a new `ProtectionOptions` object with the Excel default options.
+    ///
+    /// @returns {ProtectionOptions} - The protection options object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ProtectionOptions {
+        let options = xlsx::ProtectionOptions::new();
+        ProtectionOptions {
+            select_locked_cells: options.select_locked_cells,
+            select_unlocked_cells: options.select_unlocked_cells,
+            format_cells: options.format_cells,
+            format_columns: options.format_columns,
+            format_rows: options.format_rows,
+            insert_columns: options.insert_columns,
+            insert_rows: options.insert_rows,
+            insert_links: options.insert_links,
+            delete_columns: options.delete_columns,
+            delete_rows: options.delete_rows,
+            sort: options.sort,
+            use_autofilter: options.use_autofilter,
+            use_pivot_tables: options.use_pivot_tables,
+            edit_scenarios: options.edit_scenarios,
+            edit_objects: options.edit_objects,
+        }
+    }
+}
+
+impl From<&ProtectionOptions> for xlsx::ProtectionOptions {
+    fn from(options: &ProtectionOptions) -> xlsx::ProtectionOptions {
+        xlsx::ProtectionOptions {
+            select_locked_cells: options.select_locked_cells,
+            select_unlocked_cells: options.select_unlocked_cells,
+            format_cells: options.format_cells,
+            format_columns: options.format_columns,
+            format_rows: options.format_rows,
+            insert_columns: options.insert_columns,
+            insert_rows: options.insert_rows,
+            insert_links: options.insert_links,
+            delete_columns: options.delete_columns,
+            delete_rows: options.delete_rows,
+            sort: options.sort,
+            use_autofilter: options.use_autofilter,
+            use_pivot_tables: options.use_pivot_tables,
+            edit_scenarios: options.edit_scenarios,
+            edit_objects: options.edit_objects,
+            ..xlsx::ProtectionOptions::new()
+        }
+    }
+}
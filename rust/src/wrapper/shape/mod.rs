@@ -0,0 +1,205 @@
+mod shape_font;
+mod shape_format;
+mod shape_gradient_fill;
+mod shape_gradient_fill_type;
+mod shape_gradient_stop;
+mod shape_line;
+mod shape_line_dash_type;
+mod shape_pattern_fill;
+mod shape_pattern_fill_type;
+mod shape_solid_fill;
+mod shape_text;
+mod shape_text_direction;
+mod shape_text_horizontal_alignment;
+mod shape_text_vertical_alignment;
+
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use shape_font::ShapeFont;
+use shape_format::ShapeFormat;
+use shape_text::ShapeText;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::{formula::Formula, object_movement::ObjectMovement, url::Url, WasmResult};
+
+/// The `Shape` struct represents a worksheet shape object.
+///
+/// Currently, the only Excel shape type that is implemented is the `Textbox`
+/// shape, created with {@link Shape.textbox}.
+///
+/// <img src="https://rustxlsxwriter.github.io/images/app_textbox.png">
+///
+/// Shapes are inserted into a worksheet with the {@link Worksheet#insertShape}
+/// and {@link Worksheet#insertShapeWithOffset} methods. Note that it isn't
+/// possible to insert textboxes into other objects such as a {@link Chart}.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct Shape {
+    pub(crate) inner: Arc<Mutex<xlsx::Shape>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::Shape::textbox());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        Shape {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl Shape {
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, xlsx::Shape> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Create a new Shape object to represent an Excel Textbox shape.
+    ///
+    /// @returns {Shape} - The shape object.
+    #[wasm_bindgen(skip_jsdoc)]
+    pub fn textbox() -> Shape {
+        Shape {
+            inner: Arc::new(Mutex::new(xlsx::Shape::textbox())),
+        }
+    }
+
+    /// Set the text in the shape.
+    ///
+    /// This only applies to shapes that have a textbox option. See also
+    /// {@link Shape#setFont} and {@link Shape#setTextOptions} for formatting
+    /// options for text.
+    ///
+    /// @param {string} text - The text for the shape.
+    /// @returns {Shape} - The shape object.
+    #[wasm_bindgen(js_name = "setText", skip_jsdoc)]
+    pub fn set_text(&self, text: &str) -> Shape {
+        impl_method!(self.set_text(text))
+    }
+
+    /// Set the text in the shape from a worksheet cell.
+    ///
+    /// Set the textbox text from a link to a worksheet cell like `=A1` or
+    /// `=Sheet2!A1`.
+    ///
+    /// @param {Formula} cell - The cell from which the text is linked.
+    /// @returns {Shape} - The shape object.
+    #[wasm_bindgen(js_name = "setTextLink", skip_jsdoc)]
+    pub fn set_text_link(&self, cell: &Formula) -> Shape {
+        let cell = cell.lock().clone();
+        impl_method!(self.set_text_link(cell))
+    }
+
+    /// Set the width of the shape in pixels.
+    ///
+    /// The default width for an Excel shape is 192 pixels.
+    ///
+    /// @param {number} width - The shape width in pixels. Values less than 5
+    ///   pixels are ignored.
+    /// @returns {Shape} - The shape object.
+    #[wasm_bindgen(js_name = "setWidth", skip_jsdoc)]
+    pub fn set_width(&self, width: u32) -> Shape {
+        impl_method!(self.set_width(width))
+    }
+
+    /// Set the height of the shape in pixels.
+    ///
+    /// The default height for an Excel shape is 120 pixels.
+    ///
+    /// @param {number} height - The shape height in pixels. Values less than 5
+    ///   pixels are ignored.
+    /// @returns {Shape} - The shape object.
+    #[wasm_bindgen(js_name = "setHeight", skip_jsdoc)]
+    pub fn set_height(&self, height: u32) -> Shape {
+        impl_method!(self.set_height(height))
+    }
+
+    /// Set the formatting properties for a shape.
+    ///
+    /// Set the line and fill properties for a shape via a {@link ShapeFormat}
+    /// object.
+    ///
+    /// @param {ShapeFormat} format - The {@link ShapeFormat} properties.
+    /// @returns {Shape} - The shape object.
+    #[wasm_bindgen(js_name = "setFormat", skip_jsdoc)]
+    pub fn set_format(&self, format: &ShapeFormat) -> Shape {
+        impl_method!(self.set_format(&*format.inner.lock().unwrap()))
+    }
+
+    /// Set the font properties of the shape.
+    ///
+    /// @param {ShapeFont} font - The {@link ShapeFont} properties.
+    /// @returns {Shape} - The shape object.
+    #[wasm_bindgen(js_name = "setFont", skip_jsdoc)]
+    pub fn set_font(&self, font: &ShapeFont) -> Shape {
+        impl_method!(self.set_font(&font.inner.lock().unwrap()))
+    }
+
+    /// Set the text option properties of the shape.
+    ///
+    /// @param {ShapeText} text_options - The {@link ShapeText} options.
+    /// @returns {Shape} - The shape object.
+    #[wasm_bindgen(js_name = "setTextOptions", skip_jsdoc)]
+    pub fn set_text_options(&self, text_options: &ShapeText) -> Shape {
+        impl_method!(self.set_text_options(&text_options.inner.lock().unwrap()))
+    }
+
+    /// Set a Url/Hyperlink for a shape.
+    ///
+    /// Set a Url/Hyperlink for a shape so that when the user clicks on it they
+    /// are redirected to an internal or external location.
+    ///
+    /// @param {Url} link - The url/hyperlink associated with the shape.
+    /// @returns {Shape} - The shape object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::MaxUrlLengthExceeded`] - URL string or anchor exceeds
+    ///   Excel's limit of 2080 characters.
+    /// - [`XlsxError::UnknownUrlType`] - The URL has an unknown URI type.
+    /// - [`XlsxError::ParameterError`] - URL mouseover tool tip exceeds Excel's
+    ///   limit of 255 characters.
+    #[wasm_bindgen(js_name = "setUrl", skip_jsdoc)]
+    pub fn set_url(&self, link: &Url) -> WasmResult<Shape> {
+        let link = link.lock().clone();
+        let mut lock = self.inner.lock().unwrap();
+        *lock = lock.clone().set_url(link)?;
+        Ok(Shape {
+            inner: Arc::clone(&self.inner),
+        })
+    }
+
+    /// Set the alt text for the shape to help accessibility.
+    ///
+    /// The alt text is used with screen readers to help people with visual
+    /// disabilities.
+    ///
+    /// @param {string} alt_text - The alt text string to add to the shape.
+    /// @returns {Shape} - The shape object.
+    #[wasm_bindgen(js_name = "setAltText", skip_jsdoc)]
+    pub fn set_alt_text(&self, alt_text: &str) -> Shape {
+        impl_method!(self.set_alt_text(alt_text))
+    }
+
+    /// Set the object movement options for a worksheet shape.
+    ///
+    /// Set the option to define how a shape will behave in Excel if the cells
+    /// under the shape are moved, deleted, or have their size changed. In
+    /// Excel the options are:
+    ///
+    /// 1. Move and size with cells.
+    /// 2. Move but don't size with cells.
+    /// 3. Don't move or size with cells.
+    ///
+    /// @param {ObjectMovement} option - The object movement option.
+    /// @returns {Shape} - The shape object.
+    #[wasm_bindgen(js_name = "setObjectMovement", skip_jsdoc)]
+    pub fn set_object_movement(&self, option: ObjectMovement) -> Shape {
+        impl_method!(self.set_object_movement(option.into()))
+    }
+}
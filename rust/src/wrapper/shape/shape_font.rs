@@ -0,0 +1,153 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::color::Color;
+
+/// The `ShapeFont` struct represents the font format for shape objects.
+///
+/// Excel uses a standard font dialog for the text of a shape. It looks like
+/// this:
+///
+/// <img src="https://rustxlsxwriter.github.io/images/chart_font_dialog.png">
+///
+/// The `ShapeFont` struct represents many of these font options such as font
+/// type, size, color and properties such as bold and italic. It is used in
+/// conjunction with the {@link Shape#setFont} method.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ShapeFont {
+    pub(crate) inner: Arc<Mutex<xlsx::ShapeFont>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ShapeFont::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ShapeFont {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ShapeFont {
+    /// Create a new `ShapeFont` object to represent a Shape font.
+    ///
+    /// @returns {ShapeFont} - The shape font object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ShapeFont {
+        ShapeFont {
+            inner: Arc::new(Mutex::new(xlsx::ShapeFont::new())),
+        }
+    }
+
+    /// Set the bold property for the font of a shape element.
+    ///
+    /// @returns {ShapeFont} - The shape font object.
+    #[wasm_bindgen(js_name = "setBold", skip_jsdoc)]
+    pub fn set_bold(&self) -> ShapeFont {
+        impl_method!(self.set_bold())
+    }
+
+    /// Set the italic property for the font of a shape element.
+    ///
+    /// @returns {ShapeFont} - The shape font object.
+    #[wasm_bindgen(js_name = "setItalic", skip_jsdoc)]
+    pub fn set_italic(&self) -> ShapeFont {
+        impl_method!(self.set_italic())
+    }
+
+    /// Set the color property for the font of a shape element.
+    ///
+    /// @param {Color} color - The font color property.
+    /// @returns {ShapeFont} - The shape font object.
+    #[wasm_bindgen(js_name = "setColor", skip_jsdoc)]
+    pub fn set_color(&self, color: &Color) -> ShapeFont {
+        impl_method!(self.set_color(color.inner))
+    }
+
+    /// Set the shape font name property.
+    ///
+    /// @param {string} font_name - The font name property.
+    /// @returns {ShapeFont} - The shape font object.
+    #[wasm_bindgen(js_name = "setName", skip_jsdoc)]
+    pub fn set_name(&self, font_name: &str) -> ShapeFont {
+        impl_method!(self.set_name(font_name))
+    }
+
+    /// Set the size property for the font of a shape element.
+    ///
+    /// @param {number} font_size - The font size property.
+    /// @returns {ShapeFont} - The shape font object.
+    #[wasm_bindgen(js_name = "setSize", skip_jsdoc)]
+    pub fn set_size(&self, font_size: f64) -> ShapeFont {
+        impl_method!(self.set_size(font_size))
+    }
+
+    /// Set the underline property for the font of a shape element.
+    ///
+    /// The default underline type is the only type supported.
+    ///
+    /// @returns {ShapeFont} - The shape font object.
+    #[wasm_bindgen(js_name = "setUnderline", skip_jsdoc)]
+    pub fn set_underline(&self) -> ShapeFont {
+        impl_method!(self.set_underline())
+    }
+
+    /// Set the strikethrough property for the font of a shape element.
+    ///
+    /// @returns {ShapeFont} - The shape font object.
+    #[wasm_bindgen(js_name = "setStrikethrough", skip_jsdoc)]
+    pub fn set_strikethrough(&self) -> ShapeFont {
+        impl_method!(self.set_strikethrough())
+    }
+
+    /// Unset the bold property for a font.
+    ///
+    /// @returns {ShapeFont} - The shape font object.
+    #[wasm_bindgen(js_name = "unsetBold", skip_jsdoc)]
+    pub fn unset_bold(&self) -> ShapeFont {
+        impl_method!(self.unset_bold())
+    }
+
+    /// Display the shape text from right to left.
+    ///
+    /// See {@link Worksheet#setRightToLeft} for details.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {ShapeFont} - The shape font object.
+    #[wasm_bindgen(js_name = "setRightToLeft", skip_jsdoc)]
+    pub fn set_right_to_left(&self, enable: bool) -> ShapeFont {
+        impl_method!(self.set_right_to_left(enable))
+    }
+
+    /// Set the pitch family property for the font of a shape element.
+    ///
+    /// This function is implemented for completeness but is rarely used in
+    /// practice.
+    ///
+    /// @param {number} family - The font family property.
+    /// @returns {ShapeFont} - The shape font object.
+    #[wasm_bindgen(js_name = "setPitchFamily", skip_jsdoc)]
+    pub fn set_pitch_family(&self, family: u8) -> ShapeFont {
+        impl_method!(self.set_pitch_family(family))
+    }
+
+    /// Set the character set property for the font of a shape element.
+    ///
+    /// This function is implemented for completeness but is rarely required in
+    /// practice.
+    ///
+    /// @param {number} character_set - The font character set property.
+    /// @returns {ShapeFont} - The shape font object.
+    #[wasm_bindgen(js_name = "setCharacterSet", skip_jsdoc)]
+    pub fn set_character_set(&self, character_set: u8) -> ShapeFont {
+        impl_method!(self.set_character_set(character_set))
+    }
+}
@@ -0,0 +1,118 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use super::{
+    shape_gradient_fill::ShapeGradientFill, shape_line::ShapeLine,
+    shape_pattern_fill::ShapePatternFill, shape_solid_fill::ShapeSolidFill,
+};
+
+/// The `ShapeFormat` struct represents formatting for various shape objects.
+///
+/// Excel uses a standard formatting dialog for the shape elements which
+/// generally looks like this:
+///
+/// <img src="https://rustxlsxwriter.github.io/images/shape_format_dialog.png">
+///
+/// The `ShapeFormat` struct represents many of these format options. It is
+/// passed to the {@link Shape#setFormat} method and supports the following
+/// formatting elements:
+///
+/// - {@link ShapeFormat#setSolidFill}: Set the {@link ShapeSolidFill}
+///   properties.
+/// - {@link ShapeFormat#setPatternFill}: Set the {@link ShapePatternFill}
+///   properties.
+/// - {@link ShapeFormat#setGradientFill}: Set the {@link ShapeGradientFill}
+///   properties.
+/// - {@link ShapeFormat#setNoFill}: Turn off the fill for the shape object.
+/// - {@link ShapeFormat#setLine}: Set the {@link ShapeLine} properties.
+/// - {@link ShapeFormat#setNoLine}: Turn off the line for the shape object.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ShapeFormat {
+    pub(crate) inner: Arc<Mutex<xlsx::ShapeFormat>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ShapeFormat::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ShapeFormat {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ShapeFormat {
+    /// Create a new `ShapeFormat` instance to set formatting for a shape
+    /// element.
+    ///
+    /// @returns {ShapeFormat} - The shape format object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ShapeFormat {
+        ShapeFormat {
+            inner: Arc::new(Mutex::new(xlsx::ShapeFormat::new())),
+        }
+    }
+
+    /// Set the line formatting for a shape element.
+    ///
+    /// @param {ShapeLine} line - A {@link ShapeLine} object.
+    /// @returns {ShapeFormat} - The shape format object.
+    #[wasm_bindgen(js_name = "setLine", skip_jsdoc)]
+    pub fn set_line(&self, line: &ShapeLine) -> ShapeFormat {
+        impl_method!(self.set_line(&line.inner.lock().unwrap()))
+    }
+
+    /// Turn off the line property for a shape element.
+    ///
+    /// @returns {ShapeFormat} - The shape format object.
+    #[wasm_bindgen(js_name = "setNoLine", skip_jsdoc)]
+    pub fn set_no_line(&self) -> ShapeFormat {
+        impl_method!(self.set_no_line())
+    }
+
+    /// Set the solid fill formatting for a shape element.
+    ///
+    /// @param {ShapeSolidFill} fill - A {@link ShapeSolidFill} object.
+    /// @returns {ShapeFormat} - The shape format object.
+    #[wasm_bindgen(js_name = "setSolidFill", skip_jsdoc)]
+    pub fn set_solid_fill(&self, fill: &ShapeSolidFill) -> ShapeFormat {
+        impl_method!(self.set_solid_fill(&fill.inner.lock().unwrap()))
+    }
+
+    /// Turn off the fill property for a shape element.
+    ///
+    /// The fill property for a shape element can be turned off if you wish to
+    /// hide it and display only the border line.
+    ///
+    /// @returns {ShapeFormat} - The shape format object.
+    #[wasm_bindgen(js_name = "setNoFill", skip_jsdoc)]
+    pub fn set_no_fill(&self) -> ShapeFormat {
+        impl_method!(self.set_no_fill())
+    }
+
+    /// Set the pattern fill formatting for a shape element.
+    ///
+    /// @param {ShapePatternFill} fill - A {@link ShapePatternFill} object.
+    /// @returns {ShapeFormat} - The shape format object.
+    #[wasm_bindgen(js_name = "setPatternFill", skip_jsdoc)]
+    pub fn set_pattern_fill(&self, fill: &ShapePatternFill) -> ShapeFormat {
+        impl_method!(self.set_pattern_fill(&fill.inner.lock().unwrap()))
+    }
+
+    /// Set the gradient fill formatting for a shape element.
+    ///
+    /// @param {ShapeGradientFill} fill - A {@link ShapeGradientFill} object.
+    /// @returns {ShapeFormat} - The shape format object.
+    #[wasm_bindgen(js_name = "setGradientFill", skip_jsdoc)]
+    pub fn set_gradient_fill(&self, fill: &ShapeGradientFill) -> ShapeFormat {
+        impl_method!(self.set_gradient_fill(&fill.inner.lock().unwrap()))
+    }
+}
@@ -0,0 +1,91 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use super::{
+    shape_gradient_fill_type::ShapeGradientFillType, shape_gradient_stop::ShapeGradientStop,
+};
+
+/// The `ShapeGradientFill` struct represents a gradient fill for a shape
+/// element.
+///
+/// The `ShapeGradientFill` struct represents the formatting properties for the
+/// gradient fill of a Shape element. In Excel a gradient fill is comprised of
+/// two or more colors that are blended gradually along a gradient.
+///
+/// <img
+/// src="https://rustxlsxwriter.github.io/images/gradient_fill_options.png">
+///
+/// `ShapeGradientFill` is a sub property of the {@link ShapeFormat} struct and
+/// is used with the {@link ShapeFormat#setGradientFill} method.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ShapeGradientFill {
+    pub(crate) inner: Arc<Mutex<xlsx::ShapeGradientFill>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ShapeGradientFill::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ShapeGradientFill {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ShapeGradientFill {
+    /// Create a new `ShapeGradientFill` object to represent a Shape gradient
+    /// fill.
+    ///
+    /// @returns {ShapeGradientFill} - The gradient fill object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ShapeGradientFill {
+        ShapeGradientFill {
+            inner: Arc::new(Mutex::new(xlsx::ShapeGradientFill::new())),
+        }
+    }
+
+    /// Set the type of the gradient fill.
+    ///
+    /// Change the default type of the gradient fill to one of the styles
+    /// supported by Excel.
+    ///
+    /// @param {ShapeGradientFillType} gradient_type - A
+    ///   {@link ShapeGradientFillType} enum value.
+    /// @returns {ShapeGradientFill} - The gradient fill object.
+    #[wasm_bindgen(js_name = "setType", skip_jsdoc)]
+    pub fn set_type(&self, gradient_type: ShapeGradientFillType) -> ShapeGradientFill {
+        impl_method!(self.set_type(gradient_type.into()))
+    }
+
+    /// Set the gradient stops (data points) for a shape gradient fill.
+    ///
+    /// Excel supports between 2 and 10 gradient stops which define a color
+    /// and its position in the gradient as a percentage. Invalid stop lists
+    /// are ignored.
+    ///
+    /// @param {ShapeGradientStop[]} gradient_stops - The gradient stops.
+    /// @returns {ShapeGradientFill} - The gradient fill object.
+    #[wasm_bindgen(js_name = "setGradientStops", skip_jsdoc)]
+    pub fn set_gradient_stops(&self, gradient_stops: Vec<ShapeGradientStop>) -> ShapeGradientFill {
+        let gradient_stops: Vec<_> = gradient_stops.into_iter().map(|stop| stop.inner).collect();
+        impl_method!(self.set_gradient_stops(&gradient_stops))
+    }
+
+    /// Set the angle of the linear gradient fill type.
+    ///
+    /// @param {number} angle - The angle of the linear gradient fill in the
+    ///   range `0 <= angle < 360`. The default angle is 90 degrees.
+    /// @returns {ShapeGradientFill} - The gradient fill object.
+    #[wasm_bindgen(js_name = "setAngle", skip_jsdoc)]
+    pub fn set_angle(&self, angle: u16) -> ShapeGradientFill {
+        impl_method!(self.set_angle(angle))
+    }
+}
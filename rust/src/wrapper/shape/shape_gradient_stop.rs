@@ -0,0 +1,41 @@
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::color::Color;
+
+/// The `ShapeGradientStop` struct represents a gradient fill data point.
+///
+/// The `ShapeGradientStop` struct represents the properties of a data point (a
+/// stop) that is used to generate a gradient fill.
+///
+/// <img
+/// src="https://rustxlsxwriter.github.io/images/gradient_fill_options.png">
+///
+/// Excel supports between 2 and 10 gradient stops which define a color and
+/// its position in the gradient as a percentage. These colors and positions
+/// are used to interpolate a gradient fill.
+///
+/// Gradient stops are used with the {@link ShapeGradientFill#setGradientStops}
+/// method.
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ShapeGradientStop {
+    pub(crate) inner: xlsx::ShapeGradientStop,
+}
+
+#[wasm_bindgen]
+impl ShapeGradientStop {
+    /// Create a new `ShapeGradientStop` object to represent a Shape gradient
+    /// fill stop.
+    ///
+    /// @param {Color} color - The gradient stop color property.
+    /// @param {number} position - The gradient stop position in the range
+    ///   0-100.
+    /// @returns {ShapeGradientStop} - The gradient stop object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new(color: &Color, position: u8) -> ShapeGradientStop {
+        ShapeGradientStop {
+            inner: xlsx::ShapeGradientStop::new(color.inner, position),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::color::Color;
+
+use super::shape_line_dash_type::ShapeLineDashType;
+
+/// The `ShapeLine` struct represents a shape line/border.
+///
+/// The `ShapeLine` struct represents the formatting properties for the line of
+/// a Shape element. It is a sub property of the {@link ShapeFormat} struct and
+/// is used with the {@link ShapeFormat#setLine} method.
+///
+/// For 2D shapes the line property usually represents the border.
+///
+/// It is used in conjunction with the {@link Shape} struct.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ShapeLine {
+    pub(crate) inner: Arc<Mutex<xlsx::ShapeLine>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ShapeLine::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ShapeLine {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ShapeLine {
+    /// Create a new `ShapeLine` object to represent a Shape line/border.
+    ///
+    /// @returns {ShapeLine} - The shape line object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ShapeLine {
+        ShapeLine {
+            inner: Arc::new(Mutex::new(xlsx::ShapeLine::new())),
+        }
+    }
+
+    /// Set the color of a line/border.
+    ///
+    /// @param {Color} color - The color property.
+    /// @returns {ShapeLine} - The shape line object.
+    #[wasm_bindgen(js_name = "setColor", skip_jsdoc)]
+    pub fn set_color(&self, color: &Color) -> ShapeLine {
+        impl_method!(self.set_color(color.inner))
+    }
+
+    /// Set the width of the line or border.
+    ///
+    /// @param {number} width - The width should be specified in increments of
+    ///   0.25 of a point as in Excel. The default width is 0.75.
+    /// @returns {ShapeLine} - The shape line object.
+    #[wasm_bindgen(js_name = "setWidth", skip_jsdoc)]
+    pub fn set_width(&self, width: f64) -> ShapeLine {
+        impl_method!(self.set_width(width))
+    }
+
+    /// Set the dash type of the line or border.
+    ///
+    /// @param {ShapeLineDashType} dash_type - A {@link ShapeLineDashType} enum
+    ///   value.
+    /// @returns {ShapeLine} - The shape line object.
+    #[wasm_bindgen(js_name = "setDashType", skip_jsdoc)]
+    pub fn set_dash_type(&self, dash_type: ShapeLineDashType) -> ShapeLine {
+        impl_method!(self.set_dash_type(dash_type.into()))
+    }
+
+    /// Set the transparency of a line/border.
+    ///
+    /// Set the transparency of a line/border for a Shape element. You must also
+    /// specify a line color in order for the transparency to be applied.
+    ///
+    /// @param {number} transparency - The color transparency in the range
+    ///   0-100. The default value is 0.
+    /// @returns {ShapeLine} - The shape line object.
+    #[wasm_bindgen(js_name = "setTransparency", skip_jsdoc)]
+    pub fn set_transparency(&self, transparency: u8) -> ShapeLine {
+        impl_method!(self.set_transparency(transparency))
+    }
+
+    /// Set the shape line as hidden.
+    ///
+    /// This can also be achieved more succinctly using the
+    /// {@link ShapeFormat#setNoLine} method.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off (not
+    ///   hidden) by default.
+    /// @returns {ShapeLine} - The shape line object.
+    #[wasm_bindgen(js_name = "setHidden", skip_jsdoc)]
+    pub fn set_hidden(&self, enable: bool) -> ShapeLine {
+        impl_method!(self.set_hidden(enable))
+    }
+}
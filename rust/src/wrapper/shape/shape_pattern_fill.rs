@@ -0,0 +1,79 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::color::Color;
+
+use super::shape_pattern_fill_type::ShapePatternFillType;
+
+/// The `ShapePatternFill` struct represents the pattern fill for a shape
+/// element.
+///
+/// The `ShapePatternFill` struct represents the formatting properties for the
+/// pattern fill of a Shape element. In Excel a pattern fill is comprised of a
+/// simple pixelated pattern and background and foreground colors.
+///
+/// `ShapePatternFill` is a sub property of the {@link ShapeFormat} struct and
+/// is used with the {@link ShapeFormat#setPatternFill} method.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ShapePatternFill {
+    pub(crate) inner: Arc<Mutex<xlsx::ShapePatternFill>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ShapePatternFill::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ShapePatternFill {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ShapePatternFill {
+    /// Create a new `ShapePatternFill` object to represent a Shape pattern
+    /// fill.
+    ///
+    /// @returns {ShapePatternFill} - The pattern fill object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ShapePatternFill {
+        ShapePatternFill {
+            inner: Arc::new(Mutex::new(xlsx::ShapePatternFill::new())),
+        }
+    }
+
+    /// Set the pattern of a Shape pattern fill element.
+    ///
+    /// @param {ShapePatternFillType} pattern - The pattern property defined by
+    ///   a {@link ShapePatternFillType} enum value.
+    /// @returns {ShapePatternFill} - The pattern fill object.
+    #[wasm_bindgen(js_name = "setPattern", skip_jsdoc)]
+    pub fn set_pattern(&self, pattern: ShapePatternFillType) -> ShapePatternFill {
+        impl_method!(self.set_pattern(pattern.into()))
+    }
+
+    /// Set the background color of a Shape pattern fill element.
+    ///
+    /// @param {Color} color - The color property.
+    /// @returns {ShapePatternFill} - The pattern fill object.
+    #[wasm_bindgen(js_name = "setBackgroundColor", skip_jsdoc)]
+    pub fn set_background_color(&self, color: &Color) -> ShapePatternFill {
+        impl_method!(self.set_background_color(color.inner))
+    }
+
+    /// Set the foreground color of a Shape pattern fill element.
+    ///
+    /// @param {Color} color - The color property.
+    /// @returns {ShapePatternFill} - The pattern fill object.
+    #[wasm_bindgen(js_name = "setForegroundColor", skip_jsdoc)]
+    pub fn set_foreground_color(&self, color: &Color) -> ShapePatternFill {
+        impl_method!(self.set_foreground_color(color.inner))
+    }
+}
@@ -0,0 +1,69 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::color::Color;
+
+/// The `ShapeSolidFill` struct represents the solid fill for a shape element.
+///
+/// The `ShapeSolidFill` struct represents the formatting properties for the
+/// solid fill of a Shape element. In Excel a solid fill is a single color fill
+/// without a pattern or gradient.
+///
+/// `ShapeSolidFill` is a sub property of the {@link ShapeFormat} struct and is
+/// used with the {@link ShapeFormat#setSolidFill} method.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ShapeSolidFill {
+    pub(crate) inner: Arc<Mutex<xlsx::ShapeSolidFill>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ShapeSolidFill::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ShapeSolidFill {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ShapeSolidFill {
+    /// Create a new `ShapeSolidFill` object to represent a Shape solid fill.
+    ///
+    /// @returns {ShapeSolidFill} - The solid fill object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ShapeSolidFill {
+        ShapeSolidFill {
+            inner: Arc::new(Mutex::new(xlsx::ShapeSolidFill::new())),
+        }
+    }
+
+    /// Set the color of a solid fill.
+    ///
+    /// @param {Color} color - The color property.
+    /// @returns {ShapeSolidFill} - The solid fill object.
+    #[wasm_bindgen(js_name = "setColor", skip_jsdoc)]
+    pub fn set_color(&self, color: &Color) -> ShapeSolidFill {
+        impl_method!(self.set_color(color.inner))
+    }
+
+    /// Set the transparency of a solid fill.
+    ///
+    /// Set the transparency of a solid fill color for a Shape element. You must
+    /// also specify a fill color in order for the transparency to be applied.
+    ///
+    /// @param {number} transparency - The color transparency in the range
+    ///   0-100. The default value is 0.
+    /// @returns {ShapeSolidFill} - The solid fill object.
+    #[wasm_bindgen(js_name = "setTransparency", skip_jsdoc)]
+    pub fn set_transparency(&self, transparency: u8) -> ShapeSolidFill {
+        impl_method!(self.set_transparency(transparency))
+    }
+}
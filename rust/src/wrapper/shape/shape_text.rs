@@ -0,0 +1,94 @@
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+use super::{
+    shape_text_direction::ShapeTextDirection,
+    shape_text_horizontal_alignment::ShapeTextHorizontalAlignment,
+    shape_text_vertical_alignment::ShapeTextVerticalAlignment,
+};
+
+/// The `ShapeText` struct represents the text options for a shape element.
+///
+/// <img
+/// src="https://rustxlsxwriter.github.io/images/shape_text_options_dialog.png">
+///
+/// Currently only the vertical, horizontal and text direction properties are
+/// supported.
+///
+/// `ShapeText` is used with the {@link Shape#setTextOptions} method. See also
+/// {@link ShapeFont}.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct ShapeText {
+    pub(crate) inner: Arc<Mutex<xlsx::ShapeText>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::ShapeText::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        ShapeText {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl ShapeText {
+    /// Create a new `ShapeText` object to represent the text options for a
+    /// Shape element.
+    ///
+    /// @returns {ShapeText} - The shape text object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> ShapeText {
+        ShapeText {
+            inner: Arc::new(Mutex::new(xlsx::ShapeText::new())),
+        }
+    }
+
+    /// Set the horizontal alignment for the text in a shape textbox.
+    ///
+    /// This method sets the horizontal alignment for the text in a shape while
+    /// {@link ShapeText#setVerticalAlignment} sets the alignment for the text
+    /// bounding box.
+    ///
+    /// @param {ShapeTextHorizontalAlignment} alignment - A
+    ///   {@link ShapeTextHorizontalAlignment} enum value.
+    /// @returns {ShapeText} - The shape text object.
+    #[wasm_bindgen(js_name = "setHorizontalAlignment", skip_jsdoc)]
+    pub fn set_horizontal_alignment(&self, alignment: ShapeTextHorizontalAlignment) -> ShapeText {
+        impl_method!(self.set_horizontal_alignment(alignment.into()))
+    }
+
+    /// Set the vertical alignment for the textbox in a shape.
+    ///
+    /// This method sets the vertical alignment of the textbox in a shape while
+    /// {@link ShapeText#setHorizontalAlignment} sets the alignment for the
+    /// text within the textbox.
+    ///
+    /// @param {ShapeTextVerticalAlignment} alignment - A
+    ///   {@link ShapeTextVerticalAlignment} enum value.
+    /// @returns {ShapeText} - The shape text object.
+    #[wasm_bindgen(js_name = "setVerticalAlignment", skip_jsdoc)]
+    pub fn set_vertical_alignment(&self, alignment: ShapeTextVerticalAlignment) -> ShapeText {
+        impl_method!(self.set_vertical_alignment(alignment.into()))
+    }
+
+    /// Set the text direction of the text in the text box.
+    ///
+    /// This is useful for languages that display text vertically.
+    ///
+    /// @param {ShapeTextDirection} direction - The {@link ShapeTextDirection}
+    ///   of the text.
+    /// @returns {ShapeText} - The shape text object.
+    #[wasm_bindgen(js_name = "setDirection", skip_jsdoc)]
+    pub fn set_direction(&self, direction: ShapeTextDirection) -> ShapeText {
+        impl_method!(self.set_direction(direction.into()))
+    }
+}
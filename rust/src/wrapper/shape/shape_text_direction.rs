@@ -0,0 +1,48 @@
+use rust_xlsxwriter as xlsx;
+use wasm_bindgen::prelude::*;
+
+/// The `ShapeTextDirection` enum defines the text direction for {@link Shape}
+/// text.
+///
+/// See {@link ShapeText#setDirection}.
+#[derive(Clone, Copy, PartialEq, Eq)]
+#[wasm_bindgen]
+pub enum ShapeTextDirection {
+    /// Text is horizontal. This is the Excel default.
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/shape_text_direction_horizontal.png">
+    Horizontal,
+
+    /// Text is rotated 270 degrees.
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/shape_text_direction_rotate_270.png">
+    Rotate270,
+
+    /// Text is rotated 90 degrees.
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/shape_text_direction_rotate_90.png">
+    Rotate90,
+
+    /// Text direction is rotated 90 degrees but the characters aren't rotated.
+    /// Suitable for East Asian text.
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/shape_text_direction_rotate_90_east_asian.png">
+    Rotate90EastAsian,
+
+    /// Text is stacked vertically.
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/shape_text_direction_stacked.png">
+    Stacked,
+}
+
+impl From<ShapeTextDirection> for xlsx::ShapeTextDirection {
+    fn from(value: ShapeTextDirection) -> xlsx::ShapeTextDirection {
+        match value {
+            ShapeTextDirection::Horizontal => xlsx::ShapeTextDirection::Horizontal,
+            ShapeTextDirection::Rotate270 => xlsx::ShapeTextDirection::Rotate270,
+            ShapeTextDirection::Rotate90 => xlsx::ShapeTextDirection::Rotate90,
+            ShapeTextDirection::Rotate90EastAsian => xlsx::ShapeTextDirection::Rotate90EastAsian,
+            ShapeTextDirection::Stacked => xlsx::ShapeTextDirection::Stacked,
+        }
+    }
+}
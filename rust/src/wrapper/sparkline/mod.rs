@@ -0,0 +1,338 @@
+mod sparkline_type;
+
+use std::sync::{Arc, Mutex};
+
+use rust_xlsxwriter as xlsx;
+use sparkline_type::SparklineType;
+use wasm_bindgen::prelude::*;
+
+use crate::wrapper::chart::{ChartEmptyCells, ChartRange};
+use crate::wrapper::color::Color;
+
+/// The `Sparkline` struct is used to create an object to represent a sparkline
+/// that can be inserted into a worksheet.
+///
+/// Sparklines are a feature of Excel 2010+ which allows you to add small charts
+/// to worksheet cells. These are useful for showing data trends in a compact
+/// visual format.
+///
+/// <img src="https://rustxlsxwriter.github.io/images/sparkline_intro.png">
+///
+/// In Excel sparklines can be added as a single entity in a cell that refers to
+/// a 1D data range or as a "group" sparkline that is applied across a 1D range
+/// and refers to data in a 2D range. A grouped sparkline uses one sparkline for
+/// the specified range and any changes to it are applied to the entire
+/// sparkline group.
+///
+/// The {@link Worksheet#addSparkline} method adds a sparkline to a single cell
+/// whereas the {@link Worksheet#addSparklineGroup} method applies the group
+/// sparkline to a range.
+///
+/// TODO: example omitted
+#[derive(Clone)]
+#[wasm_bindgen]
+pub struct Sparkline {
+    pub(crate) inner: Arc<Mutex<xlsx::Sparkline>>,
+}
+
+macro_rules! impl_method {
+    ($self:ident.$method:ident($($arg:expr),*)) => {{
+        let mut lock = $self.inner.lock().unwrap();
+        let mut inner = std::mem::replace(&mut *lock, xlsx::Sparkline::new());
+        inner = inner.$method($($arg),*);
+        let _ = std::mem::replace(&mut *lock, inner);
+        Sparkline {
+            inner: Arc::clone(&$self.inner),
+        }
+    }};
+}
+
+#[wasm_bindgen]
+impl Sparkline {
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, xlsx::Sparkline> {
+        self.inner.lock().unwrap()
+    }
+
+    /// Create a new Sparkline struct.
+    ///
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(constructor, skip_jsdoc)]
+    pub fn new() -> Sparkline {
+        Sparkline {
+            inner: Arc::new(Mutex::new(xlsx::Sparkline::new())),
+        }
+    }
+
+    /// Set the range of the sparkline data.
+    ///
+    /// This method is used to set the location of the data from which the
+    /// sparkline will be plotted. This constitutes the Y values of the
+    /// sparkline.
+    ///
+    /// The range can either be a 1D range when used with
+    /// {@link Worksheet#addSparkline} or a 2D range when used with
+    /// {@link Worksheet#addSparklineGroup}.
+    ///
+    /// @param {ChartRange} range - The range that contains the data that will
+    ///   be plotted in the sparkline.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "setRange", skip_jsdoc)]
+    pub fn set_range(&self, range: &ChartRange) -> Sparkline {
+        impl_method!(self.set_range(&range.inner))
+    }
+
+    /// Set the type of sparkline.
+    ///
+    /// The type can be a line, column or win/lose sparkline. The default is
+    /// {@link SparklineType.Line}.
+    ///
+    /// @param {SparklineType} sparkline_type - The sparkline type.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "setType", skip_jsdoc)]
+    pub fn set_type(&self, sparkline_type: SparklineType) -> Sparkline {
+        impl_method!(self.set_type(sparkline_type.into()))
+    }
+
+    /// Display the highest point(s) in a sparkline with a marker.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "showHighPoint", skip_jsdoc)]
+    pub fn show_high_point(&self, enable: bool) -> Sparkline {
+        impl_method!(self.show_high_point(enable))
+    }
+
+    /// Display the lowest point(s) in a sparkline with a marker.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "showLowPoint", skip_jsdoc)]
+    pub fn show_low_point(&self, enable: bool) -> Sparkline {
+        impl_method!(self.show_low_point(enable))
+    }
+
+    /// Display the first point in a sparkline with a marker.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "showFirstPoint", skip_jsdoc)]
+    pub fn show_first_point(&self, enable: bool) -> Sparkline {
+        impl_method!(self.show_first_point(enable))
+    }
+
+    /// Display the last point in a sparkline with a marker.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "showLastPoint", skip_jsdoc)]
+    pub fn show_last_point(&self, enable: bool) -> Sparkline {
+        impl_method!(self.show_last_point(enable))
+    }
+
+    /// Display the negative points in a sparkline with markers.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "showNegativePoints", skip_jsdoc)]
+    pub fn show_negative_points(&self, enable: bool) -> Sparkline {
+        impl_method!(self.show_negative_points(enable))
+    }
+
+    /// Display markers for all points in the sparkline.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "showMarkers", skip_jsdoc)]
+    pub fn show_markers(&self, enable: bool) -> Sparkline {
+        impl_method!(self.show_markers(enable))
+    }
+
+    /// Display the horizontal axis for a sparkline.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "showAxis", skip_jsdoc)]
+    pub fn show_axis(&self, enable: bool) -> Sparkline {
+        impl_method!(self.show_axis(enable))
+    }
+
+    /// Display data from hidden rows or columns in a sparkline.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "showHiddenData", skip_jsdoc)]
+    pub fn show_hidden_data(&self, enable: bool) -> Sparkline {
+        impl_method!(self.show_hidden_data(enable))
+    }
+
+    /// Set the option for displaying empty cells in a sparkline.
+    ///
+    /// @param {ChartEmptyCells} option - How empty cells are displayed. The
+    ///   default is {@link ChartEmptyCells.Gaps}.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "showEmptyCellsAs", skip_jsdoc)]
+    pub fn show_empty_cells_as(&self, option: ChartEmptyCells) -> Sparkline {
+        impl_method!(self.show_empty_cells_as(option.into()))
+    }
+
+    /// Display the sparkline in right to left, reversed order.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "setRightToLeft", skip_jsdoc)]
+    pub fn set_right_to_left(&self, enable: bool) -> Sparkline {
+        impl_method!(self.set_right_to_left(enable))
+    }
+
+    /// Set the color of a sparkline.
+    ///
+    /// @param {Color} color - The sparkline color.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "setSparklineColor", skip_jsdoc)]
+    pub fn set_sparkline_color(&self, color: &Color) -> Sparkline {
+        impl_method!(self.set_sparkline_color(color.inner))
+    }
+
+    /// Turn on and set the color of the sparkline highest point marker.
+    ///
+    /// @param {Color} color - The marker color.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "setHighPointColor", skip_jsdoc)]
+    pub fn set_high_point_color(&self, color: &Color) -> Sparkline {
+        impl_method!(self.set_high_point_color(color.inner))
+    }
+
+    /// Turn on and set the color of the sparkline lowest point marker.
+    ///
+    /// @param {Color} color - The marker color.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "setLowPointColor", skip_jsdoc)]
+    pub fn set_low_point_color(&self, color: &Color) -> Sparkline {
+        impl_method!(self.set_low_point_color(color.inner))
+    }
+
+    /// Turn on and set the color of the sparkline first point marker.
+    ///
+    /// @param {Color} color - The marker color.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "setFirstPointColor", skip_jsdoc)]
+    pub fn set_first_point_color(&self, color: &Color) -> Sparkline {
+        impl_method!(self.set_first_point_color(color.inner))
+    }
+
+    /// Turn on and set the color of the sparkline last point marker.
+    ///
+    /// @param {Color} color - The marker color.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "setLastPointColor", skip_jsdoc)]
+    pub fn set_last_point_color(&self, color: &Color) -> Sparkline {
+        impl_method!(self.set_last_point_color(color.inner))
+    }
+
+    /// Turn on and set the color of the sparkline negative point markers.
+    ///
+    /// @param {Color} color - The marker color.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "setNegativePointsColor", skip_jsdoc)]
+    pub fn set_negative_points_color(&self, color: &Color) -> Sparkline {
+        impl_method!(self.set_negative_points_color(color.inner))
+    }
+
+    /// Turn on and set the color of the sparkline point markers.
+    ///
+    /// @param {Color} color - The marker color.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "setMarkersColor", skip_jsdoc)]
+    pub fn set_markers_color(&self, color: &Color) -> Sparkline {
+        impl_method!(self.set_markers_color(color.inner))
+    }
+
+    /// Set the weight/width of the sparkline line.
+    ///
+    /// @param {number} weight - The weight/width of the sparkline line. The
+    ///   default is 0.75.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "setLineWeight", skip_jsdoc)]
+    pub fn set_line_weight(&self, weight: f64) -> Sparkline {
+        impl_method!(self.set_line_weight(weight))
+    }
+
+    /// Set the maximum vertical value for a sparkline.
+    ///
+    /// @param {number} max - The maximum bound for the axes.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "setCustomMax", skip_jsdoc)]
+    pub fn set_custom_max(&self, max: f64) -> Sparkline {
+        impl_method!(self.set_custom_max(max))
+    }
+
+    /// Set the minimum vertical value for a sparkline.
+    ///
+    /// @param {number} min - The minimum bound for the axes.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "setCustomMin", skip_jsdoc)]
+    pub fn set_custom_min(&self, min: f64) -> Sparkline {
+        impl_method!(self.set_custom_min(min))
+    }
+
+    /// Set the maximum vertical value for a group of sparklines based on the
+    /// maximum value of the group.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "setGroupMax", skip_jsdoc)]
+    pub fn set_group_max(&self, enable: bool) -> Sparkline {
+        impl_method!(self.set_group_max(enable))
+    }
+
+    /// Set the minimum vertical value for a group of sparklines based on the
+    /// minimum value of the group.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "setGroupMin", skip_jsdoc)]
+    pub fn set_group_min(&self, enable: bool) -> Sparkline {
+        impl_method!(self.set_group_min(enable))
+    }
+
+    /// Set an optional date axis for the sparkline data.
+    ///
+    /// In general Excel graphs sparklines at equally spaced X intervals.
+    /// However, it is also possible to specify an optional range of dates that
+    /// can be used as the X values.
+    ///
+    /// @param {ChartRange} range - A 1D range that contains the dates used to
+    ///   plot the sparkline.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "setDateRange", skip_jsdoc)]
+    pub fn set_date_range(&self, range: &ChartRange) -> Sparkline {
+        impl_method!(self.set_date_range(&range.inner))
+    }
+
+    /// Change the data range order for 2D data ranges in grouped sparklines.
+    ///
+    /// The data range of a grouped sparkline is applied in row-major order,
+    /// i.e., row by row, by default. This method changes it to column-major
+    /// order.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "setColumnOrder", skip_jsdoc)]
+    pub fn set_column_order(&self, enable: bool) -> Sparkline {
+        impl_method!(self.set_column_order(enable))
+    }
+
+    /// Set the sparkline style type.
+    ///
+    /// Set the style of the sparkline to one of 36 built-in styles. The default
+    /// style is 1. Values outside the range are ignored.
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/sparkline_styles.png">
+    ///
+    /// @param {number} style - An integer value in the range 1-36.
+    /// @returns {Sparkline} - The sparkline object.
+    #[wasm_bindgen(js_name = "setStyle", skip_jsdoc)]
+    pub fn set_style(&self, style: u8) -> Sparkline {
+        impl_method!(self.set_style(style))
+    }
+}
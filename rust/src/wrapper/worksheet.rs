@@ -5,10 +5,15 @@ use wasm_bindgen::prelude::*;
 
 use crate::error::XlsxError;
 use crate::wrapper::{
-    chart::Chart, datetime::ExcelDateTime, excel_data::ExcelData, format::Format,
-    header_image_position::HeaderImagePosition, image::Image, table::Table, utils, WasmResult,
+    button::Button, chart::Chart, color::Color, data_validation::DataValidation,
+    datetime::ExcelDateTime, excel_data::ExcelData, filter::FilterCondition, format::Format,
+    header_image_position::HeaderImagePosition, ignore_error::IgnoreError, image::Image,
+    protection_options::ProtectionOptions, shape::Shape, sparkline::Sparkline, table::Table, utils,
+    WasmResult,
 };
 
+use super::conditional_format::{ConditionalFormat, JsConditionalFormat};
+
 use super::{
     excel_data::{JsExcelData, JsExcelDataArray, JsExcelDataMatrix},
     formula::Formula,
@@ -165,6 +170,69 @@ impl Worksheet {
         Ok(self.clone())
     }
 
+    /// Set the selected cell or cells in a worksheet.
+    ///
+    /// The `setSelection()` method can be used to specify which cell or range
+    /// of cells is selected in a worksheet. The most common requirement is to
+    /// select a single cell, in which case the `first_` and `last_` parameters
+    /// should be the same.
+    ///
+    /// The active cell within a selected range is determined by the order in
+    /// which `first_` and `last_` are specified.
+    ///
+    /// Only one range of cells can be selected. The default cell selection is
+    /// (0, 0, 0, 0), "A1".
+    ///
+    /// @param {number} first_row - The first row of the range. (All zero indexed.)
+    /// @param {number} first_col - The first column of the range.
+    /// @param {number} last_row - The last row of the range.
+    /// @param {number} last_col - The last column of the range.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "setSelection", skip_jsdoc)]
+    pub fn set_selection(
+        &self,
+        first_row: xlsx::RowNum,
+        first_col: xlsx::ColNum,
+        last_row: xlsx::RowNum,
+        last_col: xlsx::ColNum,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.set_selection(first_row, first_col, last_row, last_col)?;
+        Ok(self.clone())
+    }
+
+    /// Set the first visible cell at the top left of a worksheet.
+    ///
+    /// This `setTopLeftCell()` method can be used to set the top leftmost
+    /// visible cell in the worksheet. It is often used in conjunction with
+    /// {@link Worksheet#setSelection} to activate the same cell.
+    ///
+    /// @param {number} row - The zero indexed row number.
+    /// @param {number} col - The zero indexed column number.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "setTopLeftCell", skip_jsdoc)]
+    pub fn set_top_left_cell(&self, row: xlsx::RowNum, col: xlsx::ColNum) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.set_top_left_cell(row, col)?;
+        Ok(self.clone())
+    }
+
     #[wasm_bindgen(js_name = "setHeader", skip_jsdoc)]
     pub fn set_header(&self, header: &str) -> Worksheet {
         let mut book = self.workbook.lock().unwrap();
@@ -215,6 +283,49 @@ impl Worksheet {
         self.clone()
     }
 
+    /// Set a worksheet tab as selected.
+    ///
+    /// The `setSelected()` method is used to indicate that a worksheet is
+    /// selected in a multi-sheet workbook.
+    ///
+    /// A selected worksheet has its tab highlighted. Selecting worksheets is a
+    /// way of grouping them together so that, for example, several worksheets
+    /// could be printed in one go. A worksheet that has been activated via the
+    /// {@link Worksheet#setActive} method will also appear as selected.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "setSelected", skip_jsdoc)]
+    pub fn set_selected(&self, enable: bool) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.set_selected(enable);
+        self.clone()
+    }
+
+    /// Set current worksheet as the first visible sheet tab.
+    ///
+    /// The {@link Worksheet#setActive} method determines which worksheet is
+    /// initially selected. However, if there are a large number of worksheets
+    /// the selected worksheet may not appear on the screen. To avoid this you
+    /// can select which is the leftmost visible worksheet tab using
+    /// `setFirstTab()`.
+    ///
+    /// This method is not required very often. The default is the first
+    /// worksheet.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Worksheet} - The worksheet object.
+    #[wasm_bindgen(js_name = "setFirstTab", skip_jsdoc)]
+    pub fn set_first_tab(&self, enable: bool) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.set_first_tab(enable);
+        self.clone()
+    }
+
     /// Set the width for a worksheet column.
     ///
     /// The `setColumnWidth()` method is used to change the default width of a
@@ -277,6 +388,41 @@ impl Worksheet {
         Ok(self.clone())
     }
 
+    /// Set the column pixel width in auto-fit mode.
+    ///
+    /// In Excel the width of an auto-fitted column will increase if the user
+    /// edits a number and increases the number of digits past the previous
+    /// maximum width. This behavior doesn't apply to strings or when the number
+    /// of digits is decreased. It also doesn't apply to columns that have been
+    /// set manually.
+    ///
+    /// The `setColumnAutofitWidth()` method emulates this auto-fit behavior
+    /// whereas the {@link Worksheet#setColumnWidthPixels} method is equivalent
+    /// to setting the width manually. It is supported for users who wish to
+    /// implement their own version of auto-fit.
+    ///
+    /// @param {number} col - The zero indexed column number.
+    /// @param {number} width - The column width in pixels.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "setColumnAutofitWidth", skip_jsdoc)]
+    pub fn set_column_autofit_width(
+        &self,
+        col: xlsx::ColNum,
+        width: u32,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.set_column_autofit_width(col, width)?;
+        Ok(self.clone())
+    }
+
     /// Set the width for a range of columns.
     ///
     /// This is a syntactic shortcut for setting the width for a range of
@@ -308,6 +454,150 @@ impl Worksheet {
         Ok(self.clone())
     }
 
+    /// Set the pixel width for a range of columns.
+    ///
+    /// This is a syntactic shortcut for setting the width in pixels for a
+    /// range of contiguous cells. See {@link Worksheet#setColumnWidthPixels}
+    /// for more details on the single column version.
+    ///
+    /// @param {number} first_col - The first column of the range. Zero indexed.
+    /// @param {number} last_col - The last column of the range.
+    /// @param {number} width - The column width in pixels.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First column larger than the last
+    ///   column.
+    ///
+    #[wasm_bindgen(js_name = "setColumnRangeWidthPixels", skip_jsdoc)]
+    pub fn set_column_range_width_pixels(
+        &self,
+        first_col: xlsx::ColNum,
+        last_col: xlsx::ColNum,
+        width: u32,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.set_column_range_width_pixels(first_col, last_col, width)?;
+        Ok(self.clone())
+    }
+
+    /// Set the format for a column of cells.
+    ///
+    /// The `setColumnFormat()` method is used to change the default format of
+    /// a column. Any unformatted data written to that column will then adopt
+    /// that format. Formatted data written to the column will maintain its own
+    /// cell format.
+    ///
+    /// Explicit cell formatting isn't merged with the column formatting, so a
+    /// cell written with a format will only have the properties of that format.
+    ///
+    /// @param {number} col - The zero indexed column number.
+    /// @param {Format} format - The format for the column.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "setColumnFormat", skip_jsdoc)]
+    pub fn set_column_format(&self, col: xlsx::ColNum, format: &Format) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.set_column_format(col, &format.lock())?;
+        Ok(self.clone())
+    }
+
+    /// Set the format for a range of columns.
+    ///
+    /// This is a syntactic shortcut for setting the format for a range of
+    /// contiguous columns. See {@link Worksheet#setColumnFormat} for more
+    /// details on the single column version. Setting the format for all the
+    /// columns effectively sets the format for the entire worksheet.
+    ///
+    /// @param {number} first_col - The first column of the range. Zero indexed.
+    /// @param {number} last_col - The last column of the range.
+    /// @param {Format} format - The format for the columns.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First column larger than the last
+    ///   column.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "setColumnRangeFormat", skip_jsdoc)]
+    pub fn set_column_range_format(
+        &self,
+        first_col: xlsx::ColNum,
+        last_col: xlsx::ColNum,
+        format: &Format,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.set_column_range_format(first_col, last_col, &format.lock())?;
+        Ok(self.clone())
+    }
+
+    /// Hide a worksheet column.
+    ///
+    /// The `setColumnHidden()` method is used to hide a column. This can be
+    /// used, for example, to hide intermediary steps in a complicated
+    /// calculation.
+    ///
+    /// @param {number} col - The zero indexed column number.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "setColumnHidden", skip_jsdoc)]
+    pub fn set_column_hidden(&self, col: xlsx::ColNum) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.set_column_hidden(col)?;
+        Ok(self.clone())
+    }
+
+    /// Hide a range of worksheet columns.
+    ///
+    /// This is a syntactic shortcut for hiding a range of contiguous columns.
+    /// See {@link Worksheet#setColumnHidden} for more details on the single
+    /// column version.
+    ///
+    /// @param {number} first_col - The first column of the range. Zero indexed.
+    /// @param {number} last_col - The last column of the range.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First column larger than the last
+    ///   column.
+    ///
+    #[wasm_bindgen(js_name = "setColumnRangeHidden", skip_jsdoc)]
+    pub fn set_column_range_hidden(
+        &self,
+        first_col: xlsx::ColNum,
+        last_col: xlsx::ColNum,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.set_column_range_hidden(first_col, last_col)?;
+        Ok(self.clone())
+    }
+
     /// Write generic data to a cell.
     ///
     /// The `write()` method writes data of type {@link ExcelData} to a worksheet.
@@ -323,6 +613,10 @@ impl Worksheet {
     ///
     /// TODO: support bigint
     ///
+    /// A boolean is written as `TRUE` or `FALSE`. To display it as a checkbox
+    /// use {@link Worksheet#insertCheckbox}, or {@link Worksheet#writeWithFormat}
+    /// with a format that has the {@link Format#setCheckbox} property set.
+    ///
     /// @param {number} row - The zero indexed row number.
     /// @param {number} col - The zero indexed column number.
     /// @param {ExcelData} data - Data to write.
@@ -355,6 +649,10 @@ impl Worksheet {
     /// See {@link Worksheet#write} for a list of supported data types.
     /// See {@link Format} for a list of supported formatting options.
     ///
+    /// If the data is a boolean and the format has the
+    /// {@link Format#setCheckbox} property set the boolean is displayed as a
+    /// checkbox.
+    ///
     /// @param {number} row - The zero indexed row number.
     /// @param {number} col - The zero indexed column number.
     /// @param {ExcelData} data - Data to write.
@@ -628,6 +926,9 @@ impl Worksheet {
     /// the number, for example as a currency or a percentage value, or the
     /// visual format, such as bold and italic text.
     ///
+    /// If the format has the {@link Format#setCheckbox} property set the
+    /// boolean is displayed as a checkbox, see {@link Worksheet#insertCheckbox}.
+    ///
     /// @param {number} row - The zero indexed row number.
     /// @param {number} col - The zero indexed column number.
     /// @param {boolean} boolean - The boolean value to write to the cell.
@@ -652,6 +953,80 @@ impl Worksheet {
         Ok(self.clone())
     }
 
+    /// Insert a boolean checkbox in a worksheet cell.
+    ///
+    /// Checkboxes are a feature added to Excel in 2024. They are a way of
+    /// displaying a boolean value as a checkbox in a cell. The underlying value
+    /// is still an Excel `TRUE/FALSE` boolean value and can be used in formulas
+    /// and in references.
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/checkbox.png">
+    ///
+    /// The checkbox feature is only available in Excel versions from 2024 and
+    /// later. In older versions the value will be displayed as a standard Excel
+    /// `TRUE` or `FALSE` boolean. In fact Excel actually stores a checkbox as a
+    /// normal boolean but with a special format. If required you can make use
+    /// of this property to create a checkbox with
+    /// {@link Worksheet#writeBooleanWithFormat} and a cell format that has the
+    /// {@link Format#setCheckbox} property set.
+    ///
+    /// @param {number} row - The zero indexed row number.
+    /// @param {number} col - The zero indexed column number.
+    /// @param {boolean} boolean - The boolean value to display as a checkbox.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "insertCheckbox", skip_jsdoc)]
+    pub fn insert_checkbox(
+        &self,
+        row: xlsx::RowNum,
+        col: xlsx::ColNum,
+        boolean: bool,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.insert_checkbox(row, col, boolean)?;
+        Ok(self.clone())
+    }
+
+    /// Insert a boolean checkbox in a worksheet cell with a cell format.
+    ///
+    /// This method allows you to insert a boolean checkbox in a worksheet cell
+    /// with a background color or other cell format property.
+    ///
+    /// See the {@link Worksheet#insertCheckbox} method above for more details.
+    ///
+    /// @param {number} row - The zero indexed row number.
+    /// @param {number} col - The zero indexed column number.
+    /// @param {boolean} boolean - The boolean value to display as a checkbox.
+    /// @param {Format} format - The {@link Format} property for the cell.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "insertCheckboxWithFormat", skip_jsdoc)]
+    pub fn insert_checkbox_with_format(
+        &self,
+        row: xlsx::RowNum,
+        col: xlsx::ColNum,
+        boolean: bool,
+        format: &Format,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.insert_checkbox_with_format(row, col, boolean, &format.lock())?;
+        Ok(self.clone())
+    }
+
     /// Write an unformatted date and/or time to a worksheet cell.
     ///
     /// In general an unformatted date/time isn't very useful since a date in
@@ -1104,19 +1479,271 @@ impl Worksheet {
         Ok(self.clone())
     }
 
-    #[wasm_bindgen(js_name = "addTable")]
-    pub fn add_table(
+    /// Write a user defined result to a worksheet formula cell.
+    ///
+    /// The library doesn't calculate the result of a formula written using
+    /// {@link Worksheet#writeFormula} or {@link Worksheet#writeFormulaWithFormat}.
+    /// Instead it stores the value 0 as the formula result. It then sets a
+    /// global flag in the xlsx file to say that all formulas and functions
+    /// should be recalculated when the file is opened.
+    ///
+    /// This works fine with Excel and other spreadsheet applications. However,
+    /// applications that don't have a facility to calculate formulas will only
+    /// display the 0 results. If required, it is possible to specify the
+    /// calculated result of a formula using the `setFormulaResult()` method.
+    ///
+    /// You will get a warning if you try to set a formula result for a cell
+    /// that doesn't have a formula.
+    ///
+    /// @param {number} row - The zero indexed row number.
+    /// @param {number} col - The zero indexed column number.
+    /// @param {string} result - The formula result to write to the cell.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "setFormulaResult", skip_jsdoc)]
+    pub fn set_formula_result(
         &self,
-        first_row: xlsx::RowNum,
-        first_col: xlsx::ColNum,
-        last_row: xlsx::RowNum,
-        last_col: xlsx::ColNum,
-        table: &Table,
-    ) -> WasmResult<Worksheet> {
+        row: xlsx::RowNum,
+        col: xlsx::ColNum,
+        result: &str,
+    ) -> Worksheet {
         let mut book = self.workbook.lock().unwrap();
         let sheet = book.worksheet_from_index(self.index).unwrap();
-        let _ = sheet.add_table(first_row, first_col, last_row, last_col, &table.inner);
-        Ok(self.clone())
+        sheet.set_formula_result(row, col, result);
+        self.clone()
+    }
+
+    /// Write the default formula result for worksheet formulas.
+    ///
+    /// The library doesn't calculate the result of a formula and instead
+    /// stores the value 0 as the formula result, see
+    /// {@link Worksheet#setFormulaResult}. This method can be used to change
+    /// that default result for all non-calculated formulas in the worksheet.
+    ///
+    /// For LibreOffice the default formula result should be set to the empty
+    /// string literal `""` to force calculation of the result.
+    ///
+    /// @param {string} result - The default formula result to write to the
+    ///   cell.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "setFormulaResultDefault", skip_jsdoc)]
+    pub fn set_formula_result_default(&self, result: &str) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.set_formula_result_default(result);
+        self.clone()
+    }
+
+    #[wasm_bindgen(js_name = "addTable")]
+    pub fn add_table(
+        &self,
+        first_row: xlsx::RowNum,
+        first_col: xlsx::ColNum,
+        last_row: xlsx::RowNum,
+        last_col: xlsx::ColNum,
+        table: &Table,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.add_table(first_row, first_col, last_row, last_col, &table.inner);
+        Ok(self.clone())
+    }
+
+    /// Add a conditional format to highlight cells based on rules.
+    ///
+    /// Conditional formatting is a feature of Excel which allows you to apply a
+    /// format to a cell or a range of cells based on certain criteria. This is
+    /// generally used to highlight particular values in a range of data.
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/conditional_format_cell_intro.png">
+    ///
+    /// The {@link ConditionalFormat} types are used to represent the types of
+    /// conditional format that can be applied in Excel.
+    ///
+    /// Multiple formatting rules that affect the same range or overlapping
+    /// ranges are applied in the order that they are added to the worksheet.
+    ///
+    /// @param {number} first_row - The first row of the range. (All zero indexed.)
+    /// @param {number} first_col - The first column of the range.
+    /// @param {number} last_row - The last row of the range.
+    /// @param {number} last_col - The last column of the range.
+    /// @param {ConditionalFormat} conditional_format - The conditional format to apply.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
+    ///   row.
+    /// - [`XlsxError::ConditionalFormatError`] - A general error that is raised
+    ///   when a conditional formatting parameter is incorrect or missing.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "addConditionalFormat", skip_jsdoc)]
+    pub fn add_conditional_format(
+        &self,
+        first_row: xlsx::RowNum,
+        first_col: xlsx::ColNum,
+        last_row: xlsx::RowNum,
+        last_col: xlsx::ColNum,
+        conditional_format: &JsConditionalFormat,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let conditional_format: ConditionalFormat = conditional_format.try_into()?;
+        conditional_format.add_to_worksheet(sheet, first_row, first_col, last_row, last_col)?;
+        Ok(self.clone())
+    }
+
+    /// Add a data validation to one or more cells to restrict user input based
+    /// on types and rules.
+    ///
+    /// Data validation is a feature of Excel which allows you to restrict the
+    /// data that a user enters in a cell and to display associated help and
+    /// warning messages. It also allows you to restrict input to values in a
+    /// dropdown list.
+    ///
+    /// See {@link DataValidation} for more information.
+    ///
+    /// @param {number} first_row - The first row of the range. (All zero indexed.)
+    /// @param {number} first_col - The first column of the range.
+    /// @param {number} last_row - The last row of the range.
+    /// @param {number} last_col - The last column of the range.
+    /// @param {DataValidation} data_validation - The data validation to apply.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
+    ///   row.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "addDataValidation", skip_jsdoc)]
+    pub fn add_data_validation(
+        &self,
+        first_row: xlsx::RowNum,
+        first_col: xlsx::ColNum,
+        last_row: xlsx::RowNum,
+        last_col: xlsx::ColNum,
+        data_validation: &DataValidation,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.add_data_validation(
+            first_row,
+            first_col,
+            last_row,
+            last_col,
+            &data_validation.lock(),
+        )?;
+        Ok(self.clone())
+    }
+
+    /// Add a sparkline to a worksheet cell.
+    ///
+    /// Sparklines are a feature of Excel 2010+ which allows you to add small
+    /// charts to worksheet cells. These are useful for showing data trends in
+    /// a compact visual format.
+    ///
+    /// This method allows you to add a sparkline to a single cell that
+    /// displays data from a 1D range of cells. See {@link Sparkline} for the
+    /// supported options.
+    ///
+    /// @param {number} row - The zero indexed row number.
+    /// @param {number} col - The zero indexed column number.
+    /// @param {Sparkline} sparkline - The sparkline to insert into the cell.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::SparklineError`] - An error that is raised when there is
+    ///   a parameter error with the sparkline.
+    /// - [`XlsxError::ChartError`] - An error that is raised when there is a
+    ///   parameter error with the data range for the sparkline.
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::SheetnameCannotBeBlank`] - Worksheet name in the range
+    ///   cannot be blank.
+    /// - [`XlsxError::SheetnameLengthExceeded`] - Worksheet name in the range
+    ///   exceeds Excel's limit of 31 characters.
+    /// - [`XlsxError::SheetnameContainsInvalidCharacter`] - Worksheet name in
+    ///   the range cannot contain invalid characters: `[ ] : * ? / \`
+    /// - [`XlsxError::SheetnameStartsOrEndsWithApostrophe`] - Worksheet name in
+    ///   the range cannot start or end with an apostrophe.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "addSparkline", skip_jsdoc)]
+    pub fn add_sparkline(
+        &self,
+        row: xlsx::RowNum,
+        col: xlsx::ColNum,
+        sparkline: &Sparkline,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.add_sparkline(row, col, &sparkline.lock())?;
+        Ok(self.clone())
+    }
+
+    /// Add a sparkline group to a worksheet range.
+    ///
+    /// A grouped sparkline uses one sparkline for the specified 1D range of
+    /// cells and refers to data in a 2D range. Any changes to the group are
+    /// applied to all of its sparklines. See {@link Sparkline} for the
+    /// supported options.
+    ///
+    /// @param {number} first_row - The first row of the range. (All zero indexed.)
+    /// @param {number} first_col - The first column of the range.
+    /// @param {number} last_row - The last row of the range.
+    /// @param {number} last_col - The last column of the range.
+    /// @param {Sparkline} sparkline - The sparkline to insert into the range.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::SparklineError`] - An error that is raised when there is
+    ///   a parameter error with the sparkline, such as a 2D range for the
+    ///   sparkline group or a data range that doesn't match the group size.
+    /// - [`XlsxError::ChartError`] - An error that is raised when there is a
+    ///   parameter error with the data range for the sparkline.
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::SheetnameCannotBeBlank`] - Worksheet name in the range
+    ///   cannot be blank.
+    /// - [`XlsxError::SheetnameLengthExceeded`] - Worksheet name in the range
+    ///   exceeds Excel's limit of 31 characters.
+    /// - [`XlsxError::SheetnameContainsInvalidCharacter`] - Worksheet name in
+    ///   the range cannot contain invalid characters: `[ ] : * ? / \`
+    /// - [`XlsxError::SheetnameStartsOrEndsWithApostrophe`] - Worksheet name in
+    ///   the range cannot start or end with an apostrophe.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "addSparklineGroup", skip_jsdoc)]
+    pub fn add_sparkline_group(
+        &self,
+        first_row: xlsx::RowNum,
+        first_col: xlsx::ColNum,
+        last_row: xlsx::RowNum,
+        last_col: xlsx::ColNum,
+        sparkline: &Sparkline,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.add_sparkline_group(
+            first_row,
+            first_col,
+            last_row,
+            last_col,
+            &sparkline.lock(),
+        )?;
+        Ok(self.clone())
     }
 
     /// Embed an image to a worksheet and fit it to a cell.
@@ -1268,6 +1895,33 @@ impl Worksheet {
         Ok(self.clone())
     }
 
+    /// Insert a background image into a worksheet.
+    ///
+    /// A background image can be added to a worksheet to add a watermark or
+    /// display a company logo. Excel repeats the image for the entirety of the
+    /// worksheet.
+    ///
+    /// The image should be encapsulated in an {@link Image} object. See
+    /// {@link Worksheet#insertImage} above for details on the supported image
+    /// types.
+    ///
+    /// As an alternative to background images, it should be noted that the
+    /// Microsoft Excel documentation recommends setting a watermark via an
+    /// image in the worksheet header. See {@link Worksheet#setHeaderImage}.
+    ///
+    /// @param {Image} image - The {@link Image} to use as the worksheet
+    ///   background.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "insertBackgroundImage", skip_jsdoc)]
+    pub fn insert_background_image(&self, image: &Image) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.insert_background_image(&image.lock());
+        self.clone()
+    }
+
     /// Add an chart to a worksheet.
     ///
     /// Add a chart to a worksheet at a cell location. The chart should be
@@ -1320,6 +1974,136 @@ impl Worksheet {
         Ok(self.clone())
     }
 
+    /// Add an Excel Form Control button object to a worksheet.
+    ///
+    /// Add a {@link Button} to a worksheet at a cell location. The worksheet
+    /// button object is mainly provided as a way of triggering a VBA macro.
+    ///
+    /// Note, Button is the only VBA Control supported by `rust_xlsxwriter`.
+    ///
+    /// @param {number} row - The zero indexed row number.
+    /// @param {number} col - The zero indexed column number.
+    /// @param {Button} button - The {@link Button} to insert into the cell.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "insertButton", skip_jsdoc)]
+    pub fn insert_button(
+        &self,
+        row: xlsx::RowNum,
+        col: xlsx::ColNum,
+        button: &Button,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.insert_button(row, col, &button.lock())?;
+        Ok(self.clone())
+    }
+
+    /// Add an Excel Form Control button object to a worksheet at an offset.
+    ///
+    /// Add a {@link Button} to a worksheet at a pixel offset within a cell
+    /// location. See {@link Worksheet#insertButton} above.
+    ///
+    /// @param {number} row - The zero indexed row number.
+    /// @param {number} col - The zero indexed column number.
+    /// @param {Button} button - The {@link Button} to insert into the cell.
+    /// @param {number} x_offset - The horizontal offset within the cell in
+    ///   pixels.
+    /// @param {number} y_offset - The vertical offset within the cell in
+    ///   pixels.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "insertButtonWithOffset", skip_jsdoc)]
+    pub fn insert_button_with_offset(
+        &self,
+        row: xlsx::RowNum,
+        col: xlsx::ColNum,
+        button: &Button,
+        x_offset: u32,
+        y_offset: u32,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.insert_button_with_offset(row, col, &button.lock(), x_offset, y_offset)?;
+        Ok(self.clone())
+    }
+
+    /// Add a shape to a worksheet.
+    ///
+    /// Add a {@link Shape} to a worksheet at a cell location. Currently, the
+    /// only Excel shape type that is supported is the Textbox shape.
+    ///
+    /// @param {number} row - The zero indexed row number.
+    /// @param {number} col - The zero indexed column number.
+    /// @param {Shape} shape - The {@link Shape} to insert into the cell.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "insertShape", skip_jsdoc)]
+    pub fn insert_shape(
+        &self,
+        row: xlsx::RowNum,
+        col: xlsx::ColNum,
+        shape: &Shape,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.insert_shape(row, col, &shape.lock())?;
+        Ok(self.clone())
+    }
+
+    /// Add a shape to a worksheet at an offset.
+    ///
+    /// Add a {@link Shape} to a worksheet at a pixel offset within a cell
+    /// location. See {@link Worksheet#insertShape} above.
+    ///
+    /// @param {number} row - The zero indexed row number.
+    /// @param {number} col - The zero indexed column number.
+    /// @param {Shape} shape - The {@link Shape} to insert into the cell.
+    /// @param {number} x_offset - The horizontal offset within the cell in
+    ///   pixels.
+    /// @param {number} y_offset - The vertical offset within the cell in
+    ///   pixels.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "insertShapeWithOffset", skip_jsdoc)]
+    pub fn insert_shape_with_offset(
+        &self,
+        row: xlsx::RowNum,
+        col: xlsx::ColNum,
+        shape: &Shape,
+        x_offset: u32,
+        y_offset: u32,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.insert_shape_with_offset(row, col, &shape.lock(), x_offset, y_offset)?;
+        Ok(self.clone())
+    }
+
     #[wasm_bindgen(js_name = "clearCell")]
     pub fn clear_cell(&self, row: xlsx::RowNum, col: xlsx::ColNum) -> WasmResult<Worksheet> {
         let mut book = self.workbook.lock().unwrap();
@@ -1383,27 +2167,164 @@ impl Worksheet {
         self.clone()
     }
 
-    #[wasm_bindgen(js_name = "autofilter")]
-    pub fn autofilter(
-        &self,
-        first_row: xlsx::RowNum,
-        first_col: xlsx::ColNum,
-        last_row: xlsx::RowNum,
-        last_col: xlsx::ColNum,
-    ) -> WasmResult<Worksheet> {
+    /// Set the maximum row used for autofitting worksheet columns.
+    ///
+    /// The {@link Worksheet#autofit} method calculates the width of each cell
+    /// based on its contents which, for large datasets, can be an expensive
+    /// operation. The `setAutofitMaxRow()` method can be used to limit the
+    /// number of rows processed for autofitting. Since a user typically only
+    /// sees about 50 to 100 rows on a screen it is often sufficient to autofit
+    /// just the first few hundred rows.
+    ///
+    /// A value of 200 rows is recommended as a good compromise between
+    /// performance and visual accuracy.
+    ///
+    /// @param {number} max_row - The maximum row number to use for autofitting.
+    /// @returns {Worksheet} - The worksheet object.
+    #[wasm_bindgen(js_name = "setAutofitMaxRow", skip_jsdoc)]
+    pub fn set_autofit_max_row(&self, max_row: xlsx::RowNum) -> Worksheet {
         let mut book = self.workbook.lock().unwrap();
         let sheet = book.worksheet_from_index(self.index).unwrap();
-        sheet
-            .autofilter(first_row, first_col, last_row, last_col)
-            .unwrap();
-        Ok(self.clone())
+        sheet.set_autofit_max_row(max_row);
+        self.clone()
     }
 
-    /// Protect a worksheet from modification.
+    /// Set the maximum autofit width for worksheet columns.
     ///
-    /// The `protect()` method protects a worksheet from modification. It works
-    /// by enabling a cell's `locked` and `hidden` properties, if they have been
-    /// set. A **locked** cell cannot be edited and this property is on by
+    /// Excel autofits very long strings up to a limit of 255 characters/1790
+    /// pixels, which is often too wide to display on a single screen at normal
+    /// zoom. The `setAutofitMaxWidth()` method can be used to set a smaller
+    /// upper limit for the widths calculated by {@link Worksheet#autofit}.
+    ///
+    /// A value of 300 pixels is recommended as a good compromise between column
+    /// width and readability.
+    ///
+    /// @param {number} max_width - The maximum column width, in pixels, to use
+    ///   for autofitting.
+    /// @returns {Worksheet} - The worksheet object.
+    #[wasm_bindgen(js_name = "setAutofitMaxWidth", skip_jsdoc)]
+    pub fn set_autofit_max_width(&self, max_width: u32) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.set_autofit_max_width(max_width);
+        self.clone()
+    }
+
+    /// Set the autofilter area in the worksheet.
+    ///
+    /// The `autofilter()` method allows an autofilter to be added to a
+    /// worksheet. An autofilter is a way of adding drop down lists to the
+    /// headers of a 2D range of worksheet data. This allows users to filter the
+    /// data based on simple criteria so that some data is shown and some is
+    /// hidden.
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/autofilter_list.png">
+    ///
+    /// See {@link Worksheet#filterColumn} for an explanation of how to set a
+    /// filter condition for columns in the autofilter range.
+    ///
+    /// Note, Excel only allows one autofilter range per worksheet so calling
+    /// this method multiple times will overwrite the previous range.
+    ///
+    /// @param {number} first_row - The first row of the range. (All zero indexed.)
+    /// @param {number} first_col - The first column of the range.
+    /// @param {number} last_row - The last row of the range.
+    /// @param {number} last_col - The last column of the range.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
+    ///   row.
+    /// - [`XlsxError::AutofilterRangeOverlaps`] - The autofilter range overlaps
+    ///   a table autofilter range.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "autofilter", skip_jsdoc)]
+    pub fn autofilter(
+        &self,
+        first_row: xlsx::RowNum,
+        first_col: xlsx::ColNum,
+        last_row: xlsx::RowNum,
+        last_col: xlsx::ColNum,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.autofilter(first_row, first_col, last_row, last_col)?;
+        Ok(self.clone())
+    }
+
+    /// Set the filter condition for a column in an autofilter range.
+    ///
+    /// The {@link Worksheet#autofilter} method sets the cell range for an
+    /// autofilter but in order to filter rows within the filter area you must
+    /// also add a filter condition.
+    ///
+    /// Excel supports two main types of filter. The first, and most common, is
+    /// a list filter where the user selects the items to filter from a list of
+    /// all the values in the column range. The other main type of filter is a
+    /// custom filter where the user can specify 1 or 2 conditions like ">=
+    /// 4000" and "<= 6000". See {@link FilterCondition} for details.
+    ///
+    /// Note, the hiding of rows that don't match a filter is not an automatic
+    /// part of the file format. Instead the rows that don't match the filters
+    /// are hidden when the file is saved. This will be right in most cases but
+    /// it can be turned off with {@link Worksheet#filterAutomaticOff}.
+    ///
+    /// @param {number} col - The zero indexed column number.
+    /// @param {FilterCondition} filter_condition - The column filter condition.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    /// - [`XlsxError::ParameterError`] - Parameter error for the following
+    ///   issues:
+    ///   - The {@link Worksheet#autofilter} range hasn't been set.
+    ///   - The column is outside the {@link Worksheet#autofilter} range.
+    ///   - The {@link FilterCondition} doesn't have a condition set.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "filterColumn", skip_jsdoc)]
+    pub fn filter_column(
+        &self,
+        col: xlsx::ColNum,
+        filter_condition: &FilterCondition,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.filter_column(col, &filter_condition.lock())?;
+        Ok(self.clone())
+    }
+
+    /// Turn off the option to automatically hide rows that don't match
+    /// filters.
+    ///
+    /// Rows that don't match autofilter conditions are hidden by Excel at
+    /// runtime. This feature isn't an automatic part of the file format so the
+    /// rows that don't match the applied filters are hidden when the file is
+    /// saved. This will be right in most cases, however, there may be cases
+    /// where you need to manually hide some of the rows and may want to turn
+    /// off the automatic handling using `filterAutomaticOff()`.
+    ///
+    /// @returns {Worksheet} - The worksheet object.
+    #[wasm_bindgen(js_name = "filterAutomaticOff", skip_jsdoc)]
+    pub fn filter_automatic_off(&self) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.filter_automatic_off();
+        self.clone()
+    }
+
+    /// Protect a worksheet from modification.
+    ///
+    /// The `protect()` method protects a worksheet from modification. It works
+    /// by enabling a cell's `locked` and `hidden` properties, if they have been
+    /// set. A **locked** cell cannot be edited and this property is on by
     /// default for all cells. A **hidden** cell will display the results of a
     /// formula but not the formula itself.
     ///
@@ -1426,6 +2347,280 @@ impl Worksheet {
         Ok(self.clone())
     }
 
+    /// Protect a worksheet from modification with a password.
+    ///
+    /// The `protectWithPassword()` method is like the
+    /// {@link Worksheet#protect} method, see above, except that you can add an
+    /// optional, weak, password to prevent modification.
+    ///
+    /// **Note**: Worksheet level passwords in Excel offer very weak protection.
+    /// They do not encrypt your data and are very easy to deactivate.
+    ///
+    /// @param {string} password - The password string. Note, only ascii text
+    ///   passwords are supported. Passing the empty string "" is the same as
+    ///   turning on protection without a password.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "protectWithPassword", skip_jsdoc)]
+    pub fn protect_with_password(&self, password: &str) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.protect_with_password(password);
+        self.clone()
+    }
+
+    /// Specify which worksheet elements should, or shouldn't, be protected.
+    ///
+    /// The `protectWithOptions()` method is like the
+    /// {@link Worksheet#protect} method, see above, except it also specifies
+    /// which worksheet elements should, or shouldn't, be protected.
+    ///
+    /// You can specify which worksheet elements protection should be on or off
+    /// via the {@link ProtectionOptions} properties. The corresponding Excel
+    /// options with their default states are shown below:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_protect_with_options1.png">
+    ///
+    /// @param {ProtectionOptions} options - Worksheet protection options.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "protectWithOptions", skip_jsdoc)]
+    pub fn protect_with_options(&self, options: &ProtectionOptions) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.protect_with_options(&options.into());
+        self.clone()
+    }
+
+    /// Unprotect a range of cells in a protected worksheet.
+    ///
+    /// It is possible to unprotect a single cell by setting the format
+    /// `unlocked` property with {@link Format#setUnlocked}. Excel also offers an
+    /// interface to unprotect larger ranges of cells, which is replicated by
+    /// this method.
+    ///
+    /// @param {number} first_row - The first row of the range. (All zero indexed.)
+    /// @param {number} first_col - The first column of the range.
+    /// @param {number} last_row - The last row of the range.
+    /// @param {number} last_col - The last column of the range.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
+    ///   row.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "unprotectRange", skip_jsdoc)]
+    pub fn unprotect_range(
+        &self,
+        first_row: xlsx::RowNum,
+        first_col: xlsx::ColNum,
+        last_row: xlsx::RowNum,
+        last_col: xlsx::ColNum,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.unprotect_range(first_row, first_col, last_row, last_col)?;
+        Ok(self.clone())
+    }
+
+    /// Unprotect a range of cells in a protected worksheet, with options.
+    ///
+    /// This method is similar to {@link Worksheet#unprotectRange}, see above,
+    /// except that it allows you to specify two additional parameters to set
+    /// the name of the range (instead of the default `Range1` .. `RangeN`) and
+    /// also an optional weak password (see {@link Worksheet#protectWithPassword}
+    /// for an explanation of what weak means here).
+    ///
+    /// @param {number} first_row - The first row of the range. (All zero indexed.)
+    /// @param {number} first_col - The first column of the range.
+    /// @param {number} last_row - The last row of the range.
+    /// @param {number} last_col - The last column of the range.
+    /// @param {string} name - The name of the range instead of `RangeN`. Can be
+    ///   blank if not required.
+    /// @param {string} password - The password to prevent modification of the
+    ///   range. Can be blank if not required.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
+    ///   row.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "unprotectRangeWithOptions", skip_jsdoc)]
+    pub fn unprotect_range_with_options(
+        &self,
+        first_row: xlsx::RowNum,
+        first_col: xlsx::ColNum,
+        last_row: xlsx::RowNum,
+        last_col: xlsx::ColNum,
+        name: &str,
+        password: &str,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.unprotect_range_with_options(
+            first_row, first_col, last_row, last_col, name, password,
+        )?;
+        Ok(self.clone())
+    }
+
+    /// Set the default string used for NaN values.
+    ///
+    /// Excel doesn't support storing `NaN` (Not a Number) values. If a `NaN`
+    /// is generated as the result of a calculation Excel stores and displays
+    /// the error `#NUM!`. However, this error isn't usually used outside of a
+    /// formula result and it isn't stored as a number.
+    ///
+    /// In order to deal with `NaN` in a reasonable way it is written as the
+    /// string "NAN". The `setNanValue()` method allows you to override this
+    /// default value.
+    ///
+    /// @param {string} value - The string to use for NaN values.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "setNanValue", skip_jsdoc)]
+    pub fn set_nan_value(&self, value: &str) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.set_nan_value(value);
+        self.clone()
+    }
+
+    /// Set the default string used for Infinite values.
+    ///
+    /// Excel doesn't support storing `Infinity` values. In order to deal with
+    /// them in a reasonable way they are written as the string "INF". The
+    /// `setInfinityValue()` method allows you to override this default value.
+    ///
+    /// See {@link Worksheet#setNanValue} above.
+    ///
+    /// @param {string} value - The string to use for `Infinity` values.
+    /// @returns {Worksheet} - The worksheet object.
+    #[wasm_bindgen(js_name = "setInfinityValue", skip_jsdoc)]
+    pub fn set_infinity_value(&self, value: &str) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.set_infinity_value(value);
+        self.clone()
+    }
+
+    /// Set the default string used for negative Infinite values.
+    ///
+    /// Excel doesn't support storing `-Infinity` values. In order to deal with
+    /// them in a reasonable way they are written as the string "-INF". The
+    /// `setNegInfinityValue()` method allows you to override this default
+    /// value.
+    ///
+    /// See {@link Worksheet#setNanValue} above.
+    ///
+    /// @param {string} value - The string to use for `-Infinity` values.
+    /// @returns {Worksheet} - The worksheet object.
+    #[wasm_bindgen(js_name = "setNegInfinityValue", skip_jsdoc)]
+    pub fn set_neg_infinity_value(&self, value: &str) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.set_neg_infinity_value(value);
+        self.clone()
+    }
+
+    /// Ignore an Excel error or warning in a worksheet cell.
+    ///
+    /// Excel flags a number of data errors and inconsistencies with a small
+    /// green triangle in the top left hand corner of the cell. For example a
+    /// number stored as a string or a formula that divides by zero.
+    ///
+    /// The `ignoreError()` method can be used to turn off these warnings for a
+    /// single cell. The supported error types are defined by the
+    /// {@link IgnoreError} enum.
+    ///
+    /// Excel only allows one ignored error per cell.
+    ///
+    /// @param {number} row - The zero indexed row number.
+    /// @param {number} col - The zero indexed column number.
+    /// @param {IgnoreError} error_type - An {@link IgnoreError} enum value.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::ParameterError`] - Parameter error if more than one rule
+    ///   is added to the same cell.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "ignoreError", skip_jsdoc)]
+    pub fn ignore_error(
+        &self,
+        row: xlsx::RowNum,
+        col: xlsx::ColNum,
+        error_type: IgnoreError,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.ignore_error(row, col, error_type.into())?;
+        Ok(self.clone())
+    }
+
+    /// Ignore an Excel error or warning in a range of worksheet cells.
+    ///
+    /// See {@link Worksheet#ignoreError} above for an explanation of Excel
+    /// worksheet errors.
+    ///
+    /// The `ignoreErrorRange()` method can be used to ignore an error in a
+    /// range, a row, a column, or the entire worksheet. It can be called
+    /// repeatedly to ignore errors in different ranges. Excel only allows one
+    /// ignored error per cell and an error is raised if the same range is used
+    /// twice. However, overlapping ranges aren't checked.
+    ///
+    /// @param {number} first_row - The first row of the range. (All zero indexed.)
+    /// @param {number} first_col - The first column of the range.
+    /// @param {number} last_row - The last row of the range.
+    /// @param {number} last_col - The last column of the range.
+    /// @param {IgnoreError} error_type - An {@link IgnoreError} enum value.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
+    ///   than the last row or column.
+    /// - [`XlsxError::ParameterError`] - Parameter error if more than one rule
+    ///   is added to the same range.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "ignoreErrorRange", skip_jsdoc)]
+    pub fn ignore_error_range(
+        &self,
+        first_row: xlsx::RowNum,
+        first_col: xlsx::ColNum,
+        last_row: xlsx::RowNum,
+        last_col: xlsx::ColNum,
+        error_type: IgnoreError,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.ignore_error_range(
+            first_row,
+            first_col,
+            last_row,
+            last_col,
+            error_type.into(),
+        )?;
+        Ok(self.clone())
+    }
+
     /// Hide a worksheet.
     ///
     /// The `set_hidden()` method is used to hide a worksheet. This can be used
@@ -1450,79 +2645,306 @@ impl Worksheet {
         self.clone()
     }
 
-    /// Merge a range of cells.
+    /// Hide a worksheet. Can only be unhidden in Excel by VBA.
+    ///
+    /// The `setVeryHidden()` method can be used to hide a worksheet similar to
+    /// the {@link Worksheet#setHidden} method. The difference is that the
+    /// worksheet cannot be unhidden in the Excel user interface. The Excel
+    /// worksheet `xlSheetVeryHidden` option can only be unset programmatically
+    /// by VBA.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Worksheet} - The worksheet object.
+    #[wasm_bindgen(js_name = "setVeryHidden", skip_jsdoc)]
+    pub fn set_very_hidden(&self, enable: bool) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.set_very_hidden(enable);
+        self.clone()
+    }
+
+    /// Set the color of the worksheet tab.
+    ///
+    /// The `setTabColor()` method can be used to change the color of the
+    /// worksheet tab. This is useful for highlighting the important tab in a
+    /// group of worksheets.
+    ///
+    /// @param {Color} color - The tab color.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "setTabColor", skip_jsdoc)]
+    pub fn set_tab_color(&self, color: &Color) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.set_tab_color(color.inner);
+        self.clone()
+    }
+
+    /// Display the worksheet cells from right to left for some versions of
+    /// Excel.
+    ///
+    /// The `setRightToLeft()` method is used to change the default direction
+    /// of the worksheet from left-to-right, with the A1 cell in the top left,
+    /// to right-to-left, with the A1 cell in the top right.
+    ///
+    /// This is useful when creating Arabic, Hebrew or other near or far eastern
+    /// worksheets that use right-to-left as the default direction.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "setRightToLeft", skip_jsdoc)]
+    pub fn set_right_to_left(&self, enable: bool) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.set_right_to_left(enable);
+        self.clone()
+    }
+
+    /// Merge a range of cells.
+    ///
+    /// The `mergeRange()` method allows cells to be merged together so that
+    /// they act as a single area.
+    ///
+    /// The `mergeRange()` method writes a string to the merged cells. In order
+    /// to write other data types, such as a number or a formula, you can
+    /// overwrite the first cell with a call to one of the other
+    /// `worksheet.write*()` functions. The same {@link Format} instance should be
+    /// used as was used in the merged range, see the example below.
+    ///
+    /// @param {number} first_row - The first row of the range. (All zero indexed.)
+    /// @param {number} first_col - The first row of the range.
+    /// @param {number} last_row - The last row of the range.
+    /// @param {number} last_col - The last row of the range.
+    /// @param {string} value - The string to write to the cell. Other types can also be
+    ///   handled. See the documentation above and the example below.
+    /// @param {Format} format - The {@link Format} property for the cell.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row larger than the last
+    ///   row.
+    /// - [`XlsxError::MergeRangeSingleCell`] - A merge range cannot be a single
+    ///   cell in Excel.
+    /// - [`XlsxError::MergeRangeOverlaps`] - The merge range overlaps a
+    ///   previous merge range.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "mergeRange", skip_jsdoc)]
+    pub fn merge_range(
+        &self,
+        first_row: xlsx::RowNum,
+        first_col: xlsx::ColNum,
+        last_row: xlsx::RowNum,
+        last_col: xlsx::ColNum,
+        value: &str,
+        format: &Format,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.merge_range(
+            first_row,
+            first_col,
+            last_row,
+            last_col,
+            value,
+            &format.lock(),
+        )?;
+        Ok(self.clone())
+    }
+
+    #[wasm_bindgen(js_name = "setRowHeight")]
+    pub fn set_row_height(&mut self, row: xlsx::RowNum, height: f64) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.set_row_height(row, height)?;
+        Ok(self.clone())
+    }
+
+    #[wasm_bindgen(js_name = "setRowHeightPixels")]
+    pub fn set_row_height_pixels(
+        &mut self,
+        row: xlsx::RowNum,
+        height: u32,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.set_row_height_pixels(row, height)?;
+        Ok(self.clone())
+    }
+
+    /// Set the format for a row of cells.
+    ///
+    /// The `setRowFormat()` method is used to change the default format of a
+    /// row. Any unformatted data written to that row will then adopt that
+    /// format. Formatted data written to the row will maintain its own cell
+    /// format.
+    ///
+    /// Explicit cell formatting isn't merged with the row formatting, so a cell
+    /// written with a format will only have the properties of that format. If
+    /// a cell is in a formatted row and a formatted column the row format is
+    /// used.
+    ///
+    /// @param {number} row - The zero indexed row number.
+    /// @param {Format} format - The format for the row.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "setRowFormat", skip_jsdoc)]
+    pub fn set_row_format(&self, row: xlsx::RowNum, format: &Format) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.set_row_format(row, &format.lock())?;
+        Ok(self.clone())
+    }
+
+    /// Set the format for a cell without writing data to it.
+    ///
+    /// Set the format of a cell separately from writing the cell data. If the
+    /// cell already contains data, the data is kept and only the format is
+    /// changed. If a value is written to the cell later without a format, for
+    /// example with {@link Worksheet#writeString}, the cell format is replaced
+    /// by the row or column format, if any.
+    ///
+    /// @param {number} row - The zero indexed row number.
+    /// @param {number} col - The zero indexed column number.
+    /// @param {Format} format - The format for the cell.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "setCellFormat", skip_jsdoc)]
+    pub fn set_cell_format(
+        &self,
+        row: xlsx::RowNum,
+        col: xlsx::ColNum,
+        format: &Format,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.set_cell_format(row, col, &format.lock())?;
+        Ok(self.clone())
+    }
+
+    /// Hide a worksheet row.
+    ///
+    /// The `setRowHidden()` method is used to hide a row. This can be used,
+    /// for example, to hide intermediary steps in a complicated calculation.
+    ///
+    /// @param {number} row - The zero indexed row number.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "setRowHidden", skip_jsdoc)]
+    pub fn set_row_hidden(&self, row: xlsx::RowNum) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.set_row_hidden(row)?;
+        Ok(self.clone())
+    }
+
+    /// Unhide a user hidden worksheet row.
+    ///
+    /// The `setRowUnhidden()` method is used to unhide a previously hidden
+    /// row. This can occasionally be useful when used in conjunction with
+    /// autofilter rules.
+    ///
+    /// @param {number} row - The zero indexed row number.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    #[wasm_bindgen(js_name = "setRowUnhidden", skip_jsdoc)]
+    pub fn set_row_unhidden(&self, row: xlsx::RowNum) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.set_row_unhidden(row)?;
+        Ok(self.clone())
+    }
+
+    /// Set the default row height for all rows in a worksheet, efficiently.
     ///
-    /// The `mergeRange()` method allows cells to be merged together so that
-    /// they act as a single area.
+    /// This method can be used to efficiently set the default row height for
+    /// all rows in a worksheet. It is efficient because it uses an Excel
+    /// optimization to adjust the row heights with a single XML element. By
+    /// contrast, using {@link Worksheet#setRowHeight} for every row in a
+    /// worksheet would result in a very large file.
     ///
-    /// The `mergeRange()` method writes a string to the merged cells. In order
-    /// to write other data types, such as a number or a formula, you can
-    /// overwrite the first cell with a call to one of the other
-    /// `worksheet.write*()` functions. The same {@link Format} instance should be
-    /// used as was used in the merged range, see the example below.
+    /// The height is specified in character units, where the default height is
+    /// 15. Excel allows height values in increments of 0.25. Individual row
+    /// heights can be set via {@link Worksheet#setRowHeight}.
     ///
-    /// @param {number} first_row - The first row of the range. (All zero indexed.)
-    /// @param {number} first_col - The first row of the range.
-    /// @param {number} last_row - The last row of the range.
-    /// @param {number} last_col - The last row of the range.
-    /// @param {string} value - The string to write to the cell. Other types can also be
-    ///   handled. See the documentation above and the example below.
-    /// @param {Format} format - The {@link Format} property for the cell.
+    /// @param {number} height - The row height in character units. Must be
+    ///   greater than 0.0.
     /// @returns {Worksheet} - The worksheet object.
     ///
-    /// # Errors
-    ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
-    /// - [`XlsxError::RowColumnOrderError`] - First row larger than the last
-    ///   row.
-    /// - [`XlsxError::MergeRangeSingleCell`] - A merge range cannot be a single
-    ///   cell in Excel.
-    /// - [`XlsxError::MergeRangeOverlaps`] - The merge range overlaps a
-    ///   previous merge range.
-    ///
     /// TODO: example omitted
-    #[wasm_bindgen(js_name = "mergeRange", skip_jsdoc)]
-    pub fn merge_range(
-        &self,
-        first_row: xlsx::RowNum,
-        first_col: xlsx::ColNum,
-        last_row: xlsx::RowNum,
-        last_col: xlsx::ColNum,
-        value: &str,
-        format: &Format,
-    ) -> WasmResult<Worksheet> {
+    #[wasm_bindgen(js_name = "setDefaultRowHeight", skip_jsdoc)]
+    pub fn set_default_row_height(&self, height: f64) -> Worksheet {
         let mut book = self.workbook.lock().unwrap();
         let sheet = book.worksheet_from_index(self.index).unwrap();
-        let _ = sheet.merge_range(
-            first_row,
-            first_col,
-            last_row,
-            last_col,
-            value,
-            &format.lock(),
-        )?;
-        Ok(self.clone())
+        sheet.set_default_row_height(height);
+        self.clone()
     }
 
-    #[wasm_bindgen(js_name = "setRowHeight")]
-    pub fn set_row_height(&mut self, row: xlsx::RowNum, height: f64) -> WasmResult<Worksheet> {
+    /// Set the default row height in pixels for all rows in a worksheet,
+    /// efficiently.
+    ///
+    /// See {@link Worksheet#setDefaultRowHeight} above for an explanation. The
+    /// height is specified in pixels, where the default height is 20.
+    ///
+    /// @param {number} height - The row height in pixels.
+    /// @returns {Worksheet} - The worksheet object.
+    #[wasm_bindgen(js_name = "setDefaultRowHeightPixels", skip_jsdoc)]
+    pub fn set_default_row_height_pixels(&self, height: u32) -> Worksheet {
         let mut book = self.workbook.lock().unwrap();
         let sheet = book.worksheet_from_index(self.index).unwrap();
-        let _ = sheet.set_row_height(row, height)?;
-        Ok(self.clone())
+        sheet.set_default_row_height_pixels(height);
+        self.clone()
     }
 
-    #[wasm_bindgen(js_name = "setRowHeightPixels")]
-    pub fn set_row_height_pixels(
-        &mut self,
-        row: xlsx::RowNum,
-        height: u32,
-    ) -> WasmResult<Worksheet> {
+    /// Hide all unused rows in a worksheet, efficiently.
+    ///
+    /// This method can be used to efficiently hide unused rows in a worksheet.
+    /// It is efficient because it uses an Excel optimization to hide the rows
+    /// with a single XML element. By contrast, using
+    /// {@link Worksheet#setRowHidden} for the majority of rows in a worksheet
+    /// would result in a very large file.
+    ///
+    /// "Unused" in this context means that the row doesn't contain data,
+    /// formatting, or any changes such as the row height.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "hideUnusedRows", skip_jsdoc)]
+    pub fn hide_unused_rows(&self, enable: bool) -> Worksheet {
         let mut book = self.workbook.lock().unwrap();
         let sheet = book.worksheet_from_index(self.index).unwrap();
-        let _ = sheet.set_row_height_pixels(row, height)?;
-        Ok(self.clone())
+        sheet.hide_unused_rows(enable);
+        self.clone()
     }
 
     #[wasm_bindgen(js_name = "setRangeWithFormat")]
@@ -1579,6 +3001,48 @@ impl Worksheet {
         self.clone()
     }
 
+    /// Set the page view mode to normal layout.
+    ///
+    /// This method is used to display the worksheet in "View -> Normal" mode.
+    /// This is the default.
+    ///
+    /// @returns {Worksheet} - The worksheet object.
+    #[wasm_bindgen(js_name = "setViewNormal", skip_jsdoc)]
+    pub fn set_view_normal(&self) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.set_view_normal();
+        self.clone()
+    }
+
+    /// Set the page view mode to page layout.
+    ///
+    /// This method is used to display the worksheet in "View -> Page Layout"
+    /// mode.
+    ///
+    /// @returns {Worksheet} - The worksheet object.
+    #[wasm_bindgen(js_name = "setViewPageLayout", skip_jsdoc)]
+    pub fn set_view_page_layout(&self) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.set_view_page_layout();
+        self.clone()
+    }
+
+    /// Set the page view mode to page break preview.
+    ///
+    /// This method is used to display the worksheet in "View -> Page Break
+    /// Preview" mode.
+    ///
+    /// @returns {Worksheet} - The worksheet object.
+    #[wasm_bindgen(js_name = "setViewPageBreakPreview", skip_jsdoc)]
+    pub fn set_view_page_break_preview(&self) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.set_view_page_break_preview();
+        self.clone()
+    }
+
     #[wasm_bindgen(js_name = "setPaperSize")]
     pub fn set_paper_size(&self, paper_size: u8) -> Worksheet {
         let mut book = self.workbook.lock().unwrap();
@@ -1603,6 +3067,41 @@ impl Worksheet {
         self.clone()
     }
 
+    /// Set the worksheet zoom factor.
+    ///
+    /// Set the worksheet zoom factor in the range 10 <= zoom <= 400. Values
+    /// outside this range are ignored.
+    ///
+    /// The default zoom level is 100. The `setZoom()` method does not affect
+    /// the scale of the printed page in Excel. For that you should use
+    /// {@link Worksheet#setPrintScale}.
+    ///
+    /// @param {number} zoom - The worksheet zoom level.
+    /// @returns {Worksheet} - The worksheet object.
+    #[wasm_bindgen(js_name = "setZoom", skip_jsdoc)]
+    pub fn set_zoom(&self, zoom: u16) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.set_zoom(zoom);
+        self.clone()
+    }
+
+    /// Set a chartsheet to automatically zoom to fit the screen.
+    ///
+    /// This option ensures that a chartsheet is zoomed automatically by Excel
+    /// to fit the screen even when the window is resized. It doesn't have an
+    /// effect on a standard worksheet.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Worksheet} - The worksheet object.
+    #[wasm_bindgen(js_name = "setZoomToFit", skip_jsdoc)]
+    pub fn set_zoom_to_fit(&self, enable: bool) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.set_zoom_to_fit(enable);
+        self.clone()
+    }
+
     #[wasm_bindgen(js_name = "setPrintFitToPages")]
     pub fn set_print_fit_to_pages(&self, width: u16, height: u16) -> Worksheet {
         let mut book = self.workbook.lock().unwrap();
@@ -1667,6 +3166,92 @@ impl Worksheet {
         self.clone()
     }
 
+    /// Set the horizontal page breaks on a worksheet.
+    ///
+    /// The `setPageBreaks()` method adds horizontal page breaks to a
+    /// worksheet. A page break causes all the data that follows it to be
+    /// printed on the next page. Horizontal page breaks act between rows.
+    ///
+    /// @param {number[]} breaks - A list of one or more row numbers where the
+    ///   page breaks occur. To create a page break between rows 20 and 21 you
+    ///   must specify the break at row 21. However in zero index notation this
+    ///   is actually row 20. So you can pretend for a small while that you are
+    ///   using 1 index notation.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    /// - [`XlsxError::ParameterError`] - The number of page breaks exceeds
+    ///   Excel's limit of 1023 page breaks.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "setPageBreaks", skip_jsdoc)]
+    pub fn set_page_breaks(
+        &self,
+        #[wasm_bindgen(unchecked_param_type = "number[]")] breaks: Vec<xlsx::RowNum>,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        // Row 0 isn't a valid break and is ignored by `rust_xlsxwriter`, but a
+        // list with only 0 in it would panic there.
+        let breaks: Vec<_> = breaks.into_iter().filter(|&row| row != 0).collect();
+        let _ = sheet.set_page_breaks(&breaks)?;
+        Ok(self.clone())
+    }
+
+    /// Set the vertical page breaks on a worksheet.
+    ///
+    /// The `setVerticalPageBreaks()` method adds vertical page breaks to a
+    /// worksheet. This is much less common than the
+    /// {@link Worksheet#setPageBreaks} method shown above.
+    ///
+    /// @param {number[]} breaks - A list of one or more column numbers where
+    ///   the page breaks occur.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    /// - [`XlsxError::ParameterError`] - The number of page breaks exceeds
+    ///   Excel's limit of 1023 page breaks.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "setVerticalPageBreaks", skip_jsdoc)]
+    pub fn set_vertical_page_breaks(
+        &self,
+        #[wasm_bindgen(unchecked_param_type = "number[]")] breaks: Vec<u32>,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        // See `set_page_breaks()` above.
+        let breaks: Vec<_> = breaks.into_iter().filter(|&col| col != 0).collect();
+        let _ = sheet.set_vertical_page_breaks(&breaks)?;
+        Ok(self.clone())
+    }
+
+    /// Set the order in which pages are printed.
+    ///
+    /// The `setPageOrder()` method is used to change the default print
+    /// direction. This is referred to by Excel as the sheet "page order". The
+    /// default page order is "down then over":
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_page_order.png">
+    ///
+    /// @param {boolean} enable - Set `true` to get "Down, then over" (the
+    ///   default) and `false` to get "Over, then down".
+    /// @returns {Worksheet} - The worksheet object.
+    #[wasm_bindgen(js_name = "setPageOrder", skip_jsdoc)]
+    pub fn set_page_order(&self, enable: bool) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.set_page_order(enable);
+        self.clone()
+    }
+
     #[wasm_bindgen(js_name = "setPrintArea", skip_jsdoc)]
     pub fn set_print_area(
         &self,
@@ -1710,6 +3295,50 @@ impl Worksheet {
         Ok(self.clone())
     }
 
+    /// Make all worksheet notes visible when the file loads.
+    ///
+    /// By default Excel hides cell notes until the user mouses over the parent
+    /// cell. However, if required you can make all worksheet notes visible when
+    /// the worksheet loads. You can also make individual notes visible using
+    /// the {@link Note#setVisible} method.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "showAllNotes", skip_jsdoc)]
+    pub fn show_all_notes(&self, enable: bool) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.show_all_notes(enable);
+        self.clone()
+    }
+
+    /// Set the default author name for all the notes in the worksheet.
+    ///
+    /// The Note author is the creator of the note. In Excel the author name is
+    /// taken from the user name in the options/preference dialog. The note
+    /// author name appears in two places: at the start of the note text in bold
+    /// and at the bottom of the worksheet in the status bar.
+    ///
+    /// If no name is specified the default name "Author" will be applied to the
+    /// note. The author name for individual notes can be set via the
+    /// {@link Note#setAuthor} method. Alternatively this method can be used to
+    /// set the default author name for all notes in a worksheet.
+    ///
+    /// @param {string} name - The note author name. Must be less than or equal
+    ///   to the Excel limit of 52 characters.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "setDefaultNoteAuthor", skip_jsdoc)]
+    pub fn set_default_note_author(&self, name: &str) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.set_default_note_author(name);
+        self.clone()
+    }
+
     /// Group a range of rows into a worksheet outline group.
     /// # Parameters
     ///
@@ -1727,6 +3356,150 @@ impl Worksheet {
         Ok(self.clone())
     }
 
+    /// Group a range of rows into a collapsed worksheet outline group.
+    ///
+    /// In Excel an outline is a group of rows or columns that can be collapsed
+    /// or expanded to simplify hierarchical data. It is most often used with
+    /// the `SUBTOTAL()` function.
+    ///
+    /// See {@link Worksheet#groupRows} for an explanation on how to create
+    /// sub-groupings.
+    ///
+    /// @param {number} first_row - The first row of the range. Zero indexed.
+    /// @param {number} last_row - The last row of the range.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row greater than the last
+    ///   row. Note, to reverse the group direction see the
+    ///   {@link Worksheet#groupSymbolsAbove} method.
+    /// - [`XlsxError::MaxGroupLevelExceeded`] - The group would have more
+    ///   than 7 nested outline levels.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "groupRowsCollapsed", skip_jsdoc)]
+    pub fn group_rows_collapsed(
+        &self,
+        first_row: xlsx::RowNum,
+        last_row: xlsx::RowNum,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.group_rows_collapsed(first_row, last_row)?;
+        Ok(self.clone())
+    }
+
+    /// Group a range of columns into a worksheet outline group.
+    ///
+    /// In Excel an outline is a group of rows or columns that can be collapsed
+    /// or expanded to simplify hierarchical data. Groups can be nested by
+    /// calling this method for a range within an existing group, up to 7
+    /// nested outline levels.
+    ///
+    /// Excel requires outline groups at the same level to be separated by at
+    /// least one column or else it will merge them into a single group.
+    ///
+    /// @param {number} first_col - The first column of the range. Zero indexed.
+    /// @param {number} last_col - The last column of the range.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First column greater than the
+    ///   last column. Note, to reverse the group direction see the
+    ///   {@link Worksheet#groupSymbolsToLeft} method.
+    /// - [`XlsxError::MaxGroupLevelExceeded`] - The group would have more
+    ///   than 7 nested outline levels.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "groupColumns", skip_jsdoc)]
+    pub fn group_columns(
+        &self,
+        first_col: xlsx::ColNum,
+        last_col: xlsx::ColNum,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.group_columns(first_col, last_col)?;
+        Ok(self.clone())
+    }
+
+    /// Group a range of columns into a collapsed worksheet outline group.
+    ///
+    /// See {@link Worksheet#groupColumns} for an explanation on how to create
+    /// sub-groupings.
+    ///
+    /// @param {number} first_col - The first column of the range. Zero indexed.
+    /// @param {number} last_col - The last column of the range.
+    /// @returns {Worksheet} - The worksheet object.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First column greater than the
+    ///   last column. Note, to reverse the group direction see the
+    ///   {@link Worksheet#groupSymbolsToLeft} method.
+    /// - [`XlsxError::MaxGroupLevelExceeded`] - The group would have more
+    ///   than 7 nested outline levels.
+    ///
+    /// TODO: example omitted
+    #[wasm_bindgen(js_name = "groupColumnsCollapsed", skip_jsdoc)]
+    pub fn group_columns_collapsed(
+        &self,
+        first_col: xlsx::ColNum,
+        last_col: xlsx::ColNum,
+    ) -> WasmResult<Worksheet> {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        let _ = sheet.group_columns_collapsed(first_col, last_col)?;
+        Ok(self.clone())
+    }
+
+    /// Place the row outline group expand/collapse symbols above the range.
+    ///
+    /// This method toggles the Excel worksheet option to place the outline
+    /// group expand/collapse symbols `[+]` and `[-]` above the group ranges
+    /// instead of below for row ranges.
+    ///
+    /// In Excel this is a worksheet wide option and will apply to all row
+    /// outlines in the worksheet.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Worksheet} - The worksheet object.
+    #[wasm_bindgen(js_name = "groupSymbolsAbove", skip_jsdoc)]
+    pub fn group_symbols_above(&self, enable: bool) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.group_symbols_above(enable);
+        self.clone()
+    }
+
+    /// Place the column outline group expand/collapse symbols to the left of
+    /// the range.
+    ///
+    /// This method toggles the Excel worksheet option to place the outline
+    /// group expand/collapse symbols `[+]` and `[-]` to the left of the group
+    /// ranges instead of to the right, for column ranges.
+    ///
+    /// In Excel this is a worksheet wide option and will apply to all column
+    /// outlines in the worksheet.
+    ///
+    /// @param {boolean} enable - Turn the property on/off. It is off by default.
+    /// @returns {Worksheet} - The worksheet object.
+    #[wasm_bindgen(js_name = "groupSymbolsToLeft", skip_jsdoc)]
+    pub fn group_symbols_to_left(&self, enable: bool) -> Worksheet {
+        let mut book = self.workbook.lock().unwrap();
+        let sheet = book.worksheet_from_index(self.index).unwrap();
+        sheet.group_symbols_to_left(enable);
+        self.clone()
+    }
+
     /// Set the worksheet margins for the printed page.
     ///
     /// The `setMargins()` method is used to set the margins of the worksheet
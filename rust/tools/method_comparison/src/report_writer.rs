@@ -28,6 +28,23 @@ pub enum MigrationStatus {
     NotMigrated,
 }
 
+/// Notes for rust_xlsxwriter items that are intentionally covered by a
+/// different API in wasm-xlsxwriter, so they aren't mistaken for gaps.
+const NOTES: &[(&str, &str)] = &[(
+    "FilterData",
+    "Covered by the `FilterData` TypeScript union (`string | number`). \
+     `new_number_and_criteria` and `new_string_and_criteria` are intentionally \
+     replaced by the `FilterCondition` methods, which take the value and the \
+     `FilterCriteria` directly.",
+)];
+
+fn find_note(name: &str) -> Option<&'static str> {
+    NOTES
+        .iter()
+        .find(|(note_name, _)| *note_name == name)
+        .map(|(_, note)| *note)
+}
+
 pub fn write_comparison_report(comparison: &ComparisonResults, output_file: &str) -> Result<()> {
     let mut report = String::new();
 
@@ -115,6 +132,10 @@ pub fn write_comparison_report(comparison: &ComparisonResults, output_file: &str
 
         report.push_str(&format!("  ### {} {}\n", status_icon, struct_comp.name));
 
+        if let Some(note) = find_note(&struct_comp.name) {
+            report.push_str(&format!("    ℹ️ Note: {}\n", note));
+        }
+
         report.push_str("    Summary\n");
         report.push_str(&format!(
             "      - Migrated methods: {}\n",
@@ -0,0 +1,91 @@
+import { FilterCondition, FilterCriteria, Workbook } from "wasm-xlsxwriter/web";
+import { describe, test, beforeAll, expect } from "vitest";
+import { initWasModule, readXlsx, readXlsxFile } from "./common";
+
+beforeAll(async () => {
+  await initWasModule();
+});
+
+describe("xlsx-wasm test", () => {
+  test("autofilter with filter conditions", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    worksheet.writeRow(0, 0, ["Region", "Item", "Sales"]);
+    const regions = ["East", "West", "East", "North", "South", "West", "", "East"];
+    const items = ["Apple", "Pear", "Grape", "Apple", "Banana", "Orange", "Pear", "Plum"];
+    const sales = [3000, 8000, 5000, 4000, 7000, 9000, 2000, 6000];
+    regions.forEach((region, i) => {
+      if (region !== "") {
+        worksheet.writeString(i + 1, 0, region);
+      }
+      worksheet.writeString(i + 1, 1, items[i]);
+      worksheet.writeNumber(i + 1, 2, sales[i]);
+    });
+
+    // Act
+    worksheet.autofilter(0, 0, 8, 2);
+    worksheet.filterColumn(
+      0,
+      new FilterCondition()
+        .addListFilter("East")
+        .addListFilter("West")
+        .addListBlanksFilter(),
+    );
+    worksheet.filterColumn(
+      1,
+      new FilterCondition().addCustomFilter(FilterCriteria.DoesNotBeginWith, "B"),
+    );
+    worksheet.filterColumn(
+      2,
+      new FilterCondition()
+        .addCustomFilter(FilterCriteria.LessThan, 4000)
+        .addCustomFilter(FilterCriteria.GreaterThanOrEqualTo, 6000)
+        .addCustomBooleanOr(),
+    );
+    const worksheet2 = workbook.addWorksheet();
+    worksheet2.writeColumn(0, 0, ["Sales", "1", "2"]);
+    worksheet2.autofilter(0, 0, 2, 0);
+    worksheet2.filterColumn(0, new FilterCondition().addListFilter(1));
+    worksheet2.filterAutomaticOff();
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/autofilter.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+
+  test("autofilter with an invalid range", () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+
+    // Act & Assert
+    expect(() => {
+      worksheet.autofilter(8, 0, 0, 2);
+    }).toThrow("XlsxError(RowColumnOrderError)");
+  });
+
+  test("filter column without an autofilter range", () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+
+    // Act & Assert
+    expect(() => {
+      worksheet.filterColumn(0, new FilterCondition().addListFilter("East"));
+    }).toThrow("XlsxError(ParameterError");
+  });
+
+  test("filter column without a condition", () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    worksheet.autofilter(0, 0, 8, 2);
+
+    // Act & Assert
+    expect(() => {
+      worksheet.filterColumn(0, new FilterCondition());
+    }).toThrow("XlsxError(ParameterError");
+  });
+});
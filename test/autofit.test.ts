@@ -0,0 +1,45 @@
+import { Workbook } from "wasm-xlsxwriter/web";
+import { describe, test, beforeAll, expect } from "vitest";
+import { initWasModule, readXlsx, readXlsxFile } from "./common";
+
+beforeAll(async () => {
+  await initWasModule();
+});
+
+describe("xlsx-wasm test", () => {
+  test("autofit with options", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    worksheet.writeRow(0, 0, ["Name", "Description", "Amount", "Notes"]);
+    worksheet.writeRow(1, 0, ["Apple", "A short description", "", ""]);
+    worksheet.writeNumber(1, 2, 1234567);
+    worksheet.writeRow(2, 0, [
+      "Banana",
+      "A much much much much much longer description that is ignored",
+    ]);
+    worksheet.writeString(3, 3, "This column has a fixed autofit width");
+
+    // Act
+    worksheet.setAutofitMaxRow(2);
+    worksheet.setAutofitMaxWidth(80);
+    worksheet.autofit();
+    worksheet.setColumnAutofitWidth(3, 80);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/autofit.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+
+  test("set column autofit width with invalid column", () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+
+    // Act & Assert
+    expect(() => {
+      worksheet.setColumnAutofitWidth(16384, 80);
+    }).toThrow("XlsxError(RowColumnLimitError)");
+  });
+});
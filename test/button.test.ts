@@ -0,0 +1,32 @@
+import { Button, ObjectMovement, Workbook } from "wasm-xlsxwriter/web";
+import { describe, test, beforeAll, expect } from "vitest";
+import { initWasModule, readXlsx, readXlsxFile } from "./common";
+
+beforeAll(async () => {
+  await initWasModule();
+});
+
+describe("xlsx-wasm test", () => {
+  test("insert buttons", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    worksheet.writeString(2, 0, "Press the button to say hello:");
+
+    // Act
+    const button = new Button()
+      .setCaption("Press Me")
+      .setMacro("say_hello")
+      .setWidth(80)
+      .setHeight(30)
+      .setAltText("Say hello")
+      .setObjectMovement(ObjectMovement.DontMoveOrSizeWithCells);
+    worksheet.insertButton(2, 1, button);
+    worksheet.insertButtonWithOffset(4, 1, new Button(), 10, 5);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/button.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+});
@@ -0,0 +1,48 @@
+import { Color, Format, Workbook } from "wasm-xlsxwriter/web";
+import { describe, test, beforeAll, expect } from "vitest";
+import { initWasModule, readXlsx, readXlsxFile } from "./common";
+
+beforeAll(async () => {
+  await initWasModule();
+});
+
+describe("xlsx-wasm test", () => {
+  test("insert checkboxes", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    worksheet.writeRow(0, 0, ["Task", "Done"]);
+    worksheet.writeString(1, 0, "Write report");
+    worksheet.writeString(2, 0, "Review report");
+    worksheet.writeString(3, 0, "Publish report");
+
+    // Act
+    worksheet.insertCheckbox(1, 1, true);
+    const red = new Format().setBackgroundColor(Color.rgb(0xffc7ce));
+    worksheet.insertCheckboxWithFormat(2, 1, false, red);
+    const checkbox = new Format().setCheckbox();
+    worksheet.writeBooleanWithFormat(3, 1, false, checkbox);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/checkbox.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+
+  test("write booleans as checkboxes", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+
+    // Act
+    const checkbox = new Format().setCheckbox();
+    worksheet.writeWithFormat(0, 0, true, checkbox);
+    worksheet.writeWithFormat(1, 0, false, checkbox);
+    worksheet.write(2, 0, true);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/checkbox_write.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+});
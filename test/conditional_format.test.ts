@@ -0,0 +1,324 @@
+import {
+  Color,
+  ConditionalFormat2ColorScale,
+  ConditionalFormat3ColorScale,
+  ConditionalFormatAverage,
+  ConditionalFormatAverageRule,
+  ConditionalFormatBlank,
+  ConditionalFormatCell,
+  ConditionalFormatCellRule,
+  ConditionalFormatCustomIcon,
+  ConditionalFormatDataBar,
+  ConditionalFormatDataBarAxisPosition,
+  ConditionalFormatDataBarDirection,
+  ConditionalFormatDate,
+  ConditionalFormatDateRule,
+  ConditionalFormatDuplicate,
+  ConditionalFormatError,
+  ConditionalFormatFormula,
+  ConditionalFormatIconSet,
+  ConditionalFormatIconType,
+  ConditionalFormatText,
+  ConditionalFormatTextRule,
+  ConditionalFormatTop,
+  ConditionalFormatTopRule,
+  ConditionalFormatType,
+  Format,
+  Formula,
+  Workbook,
+} from "wasm-xlsxwriter/web";
+import { describe, test, beforeAll, expect } from "vitest";
+import { initWasModule, readXlsx, readXlsxFile } from "./common";
+
+beforeAll(async () => {
+  await initWasModule();
+});
+
+describe("xlsx-wasm test", () => {
+  test("add conditional format color scale", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    const data = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    for (let col = 0; col < 4; col++) {
+      worksheet.writeColumn(0, col, data);
+    }
+
+    // Act
+    worksheet.addConditionalFormat(0, 0, 9, 0, new ConditionalFormat2ColorScale());
+    const twoColor = new ConditionalFormat2ColorScale()
+      .setMinimum(ConditionalFormatType.Number, 3)
+      .setMaximum(ConditionalFormatType.Number, 7)
+      .setMinimumColor(Color.rgb(0x63be7b))
+      .setMaximumColor(Color.rgb(0xffef9c));
+    worksheet.addConditionalFormat(0, 1, 9, 1, twoColor);
+    const threeColor = new ConditionalFormat3ColorScale()
+      .setMidpoint(ConditionalFormatType.Percent, 40)
+      .setMidpointColor(Color.white());
+    worksheet.addConditionalFormat(0, 2, 9, 2, threeColor);
+    const multiRange = new ConditionalFormat3ColorScale()
+      .setMinimum(ConditionalFormatType.Formula, new Formula("=$E$1"))
+      .setMultiRange("D1:D10 F1:F10");
+    worksheet.addConditionalFormat(0, 3, 9, 3, multiRange);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/conditional_format_color_scale.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+
+  test("add conditional format data bar", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    const data = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    for (let col = 0; col < 3; col++) {
+      worksheet.writeColumn(0, col, data);
+    }
+    worksheet.writeColumn(0, 3, data.map((n) => -n));
+
+    // Act
+    worksheet.addConditionalFormat(0, 0, 9, 0, new ConditionalFormatDataBar());
+    const solid = new ConditionalFormatDataBar()
+      .setMinimum(ConditionalFormatType.Number, 2)
+      .setMaximum(ConditionalFormatType.Percentile, 90)
+      .setFillColor(Color.rgb(0x009933))
+      .setBorderColor(Color.rgb(0x000000))
+      .setSolidFill(true);
+    worksheet.addConditionalFormat(0, 1, 9, 1, solid);
+    const barOnly = new ConditionalFormatDataBar()
+      .setDirection(ConditionalFormatDataBarDirection.RightToLeft)
+      .setBorderOff(true)
+      .setBarOnly(true);
+    worksheet.addConditionalFormat(0, 2, 9, 2, barOnly);
+    const negative = new ConditionalFormatDataBar()
+      .setNegativeFillColor(Color.rgb(0xffc000))
+      .setNegativeBorderColor(Color.rgb(0xc00000))
+      .setAxisPosition(ConditionalFormatDataBarAxisPosition.Midpoint)
+      .setAxisColor(Color.rgb(0x0000ff));
+    worksheet.addConditionalFormat(0, 3, 9, 3, negative);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/conditional_format_data_bar.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+
+  test("add conditional format data bar with classic style", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    const data = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    worksheet.writeColumn(0, 0, data);
+    worksheet.writeColumn(0, 1, data);
+
+    // Act
+    const classic = new ConditionalFormatDataBar().useClassicStyle();
+    worksheet.addConditionalFormat(0, 0, 9, 0, classic);
+    const colored = new ConditionalFormatDataBar()
+      .setFillColor(Color.rgb(0x009933))
+      .useClassicStyle();
+    worksheet.addConditionalFormat(0, 1, 9, 1, colored);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/conditional_format_data_bar_classic.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+
+  test("add conditional format icon set", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    for (let row = 0; row < 4; row++) {
+      worksheet.writeRow(row, 0, [1, 2, 3, 4, 5]);
+    }
+
+    // Act
+    worksheet.addConditionalFormat(0, 0, 0, 4, new ConditionalFormatIconSet());
+    const arrows = new ConditionalFormatIconSet()
+      .setIconType(ConditionalFormatIconType.FiveArrows)
+      .reverseIcons(true)
+      .showIconsOnly(true);
+    worksheet.addConditionalFormat(1, 0, 1, 4, arrows);
+    const rules = new ConditionalFormatIconSet()
+      .setIconType(ConditionalFormatIconType.ThreeArrows)
+      .setIcons([
+        new ConditionalFormatCustomIcon(),
+        new ConditionalFormatCustomIcon()
+          .setRule(ConditionalFormatType.Percentile, 50)
+          .setGreaterThan(true),
+        new ConditionalFormatCustomIcon().setRule(ConditionalFormatType.Number, 4),
+      ]);
+    worksheet.addConditionalFormat(2, 0, 2, 4, rules);
+    const custom = new ConditionalFormatIconSet()
+      .setIconType(ConditionalFormatIconType.ThreeSymbols)
+      .setIcons([
+        new ConditionalFormatCustomIcon().setNoIcon(true),
+        new ConditionalFormatCustomIcon().setIconType(ConditionalFormatIconType.FiveBoxes, 4),
+        new ConditionalFormatCustomIcon().setIconType(ConditionalFormatIconType.ThreeStars, 2),
+      ]);
+    worksheet.addConditionalFormat(3, 0, 3, 4, custom);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/conditional_format_icon_set.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+
+  test("add conditional format icon set with mismatched icons", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    const iconSet = new ConditionalFormatIconSet()
+      .setIconType(ConditionalFormatIconType.FourArrows)
+      .setIcons([new ConditionalFormatCustomIcon(), new ConditionalFormatCustomIcon()]);
+
+    // Act & Assert
+    expect(() => {
+      worksheet.addConditionalFormat(0, 0, 0, 3, iconSet);
+    }).toThrow("XlsxError(ConditionalFormatError");
+  });
+
+  test("add conditional format cell, text and date rules", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    const red = new Format().setFontColor(Color.rgb(0x9c0006)).setBackgroundColor(Color.rgb(0xffc7ce));
+    const green = new Format().setFontColor(Color.rgb(0x006100)).setBackgroundColor(Color.rgb(0xc6efce));
+    const data = [10, 20, 30, 40, 50, 60, 70, 80, 90, 100];
+    worksheet.writeColumn(0, 0, data);
+    worksheet.writeColumn(0, 1, data);
+    worksheet.writeColumn(0, 2, ["open", "overdue", "done", "Overdue", "open"]);
+
+    // Act
+    const greaterThan = new ConditionalFormatCell()
+      .setRule(ConditionalFormatCellRule.greaterThan(50))
+      .setFormat(red);
+    worksheet.addConditionalFormat(0, 0, 9, 0, greaterThan);
+    const between = new ConditionalFormatCell()
+      .setRule(ConditionalFormatCellRule.between(new Formula("=$E$1"), 80))
+      .setFormat(green);
+    worksheet.addConditionalFormat(0, 1, 9, 1, between);
+    const equalTo = new ConditionalFormatCell()
+      .setRule(ConditionalFormatCellRule.equalTo("done"))
+      .setFormat(green);
+    worksheet.addConditionalFormat(0, 2, 4, 2, equalTo);
+    const contains = new ConditionalFormatText()
+      .setRule(ConditionalFormatTextRule.contains("overdue"))
+      .setFormat(red);
+    worksheet.addConditionalFormat(0, 2, 4, 2, contains);
+    const last7Days = new ConditionalFormatDate()
+      .setRule(ConditionalFormatDateRule.Last7Days)
+      .setFormat(green);
+    worksheet.addConditionalFormat(0, 3, 9, 3, last7Days);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/conditional_format_cell_text_date.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+
+  test("add conditional format cell without a rule", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+
+    // Act & Assert
+    expect(() => {
+      worksheet.addConditionalFormat(0, 0, 9, 0, new ConditionalFormatCell());
+    }).toThrow("XlsxError(ConditionalFormatError");
+  });
+
+  test("add conditional format statistical rules", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    const format = new Format().setBackgroundColor(Color.rgb(0xffc7ce));
+    const data = [34, 72, 15, 72, 58, 9, 41, 87, 23, 66];
+    for (let col = 0; col < 5; col++) {
+      worksheet.writeColumn(0, col, data);
+    }
+    worksheet.writeFormula(3, 6, new Formula("=1/0"));
+
+    // Act
+    worksheet.addConditionalFormat(0, 0, 9, 0, new ConditionalFormatTop().setFormat(format));
+    const bottom = new ConditionalFormatTop()
+      .setRule(ConditionalFormatTopRule.bottomPercent(20))
+      .setFormat(format);
+    worksheet.addConditionalFormat(0, 1, 9, 1, bottom);
+    worksheet.addConditionalFormat(0, 2, 9, 2, new ConditionalFormatAverage().setFormat(format));
+    const belowStdDev = new ConditionalFormatAverage()
+      .setRule(ConditionalFormatAverageRule.OneStandardDeviationBelow)
+      .setFormat(format);
+    worksheet.addConditionalFormat(0, 3, 9, 3, belowStdDev);
+    worksheet.addConditionalFormat(0, 4, 9, 4, new ConditionalFormatDuplicate().setFormat(format));
+    worksheet.addConditionalFormat(0, 4, 9, 4, new ConditionalFormatDuplicate().invert().setFormat(format));
+    worksheet.addConditionalFormat(0, 5, 9, 5, new ConditionalFormatBlank().setFormat(format));
+    worksheet.addConditionalFormat(0, 5, 9, 5, new ConditionalFormatBlank().invert().setFormat(format));
+    worksheet.addConditionalFormat(0, 6, 9, 6, new ConditionalFormatError().setFormat(format));
+    worksheet.addConditionalFormat(0, 6, 9, 6, new ConditionalFormatError().invert().setFormat(format));
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/conditional_format_statistical.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+
+  test("add conditional format top with an out of range rank", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    const top = new ConditionalFormatTop().setRule(ConditionalFormatTopRule.top(1001));
+
+    // Act & Assert
+    expect(() => {
+      worksheet.addConditionalFormat(0, 0, 9, 0, top);
+    }).toThrow("XlsxError(ConditionalFormatError");
+  });
+
+  test("add conditional format formula with stop if true and multi range", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    const red = new Format().setBackgroundColor(Color.rgb(0xffc7ce));
+    const green = new Format().setBackgroundColor(Color.rgb(0xc6efce));
+    worksheet.writeRow(0, 0, ["Item", "Region", "Budget", "Actual", "Forecast"]);
+    for (let row = 1; row < 6; row++) {
+      worksheet.writeRow(row, 2, [100 * row, 90 * row + 20, 110 * row]);
+    }
+
+    // Act
+    const formula = new ConditionalFormatFormula()
+      .setRule("=$D2>$E2")
+      .setFormat(red)
+      .setStopIfTrue(true)
+      .setMultiRange("A2:B6 D2:D6");
+    worksheet.addConditionalFormat(1, 0, 5, 1, formula);
+    const cell = new ConditionalFormatCell()
+      .setRule(ConditionalFormatCellRule.greaterThan(200))
+      .setFormat(green)
+      .setMultiRange("C2:C6 E2:E6");
+    worksheet.addConditionalFormat(1, 2, 5, 2, cell);
+    const dataBar = new ConditionalFormatDataBar()
+      .setStopIfTrue(true)
+      .setMultiRange("$C$2:$C$6,$E$2:$E$6");
+    worksheet.addConditionalFormat(1, 2, 5, 2, dataBar);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/conditional_format_formula.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+
+  test("add conditional format with an invalid range", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+
+    // Act & Assert
+    expect(() => {
+      worksheet.addConditionalFormat(9, 0, 0, 0, new ConditionalFormat2ColorScale());
+    }).toThrow("XlsxError(RowColumnOrderError)");
+  });
+});
@@ -0,0 +1,101 @@
+import {
+  DataValidation,
+  DataValidationErrorStyle,
+  DataValidationRule,
+  ExcelDateTime,
+  Formula,
+  Workbook,
+} from "wasm-xlsxwriter/web";
+import { describe, test, beforeAll, expect } from "vitest";
+import { initWasModule, readXlsx, readXlsxFile } from "./common";
+
+beforeAll(async () => {
+  await initWasModule();
+});
+
+describe("xlsx-wasm test", () => {
+  test("add data validation", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+
+    // Act
+    const wholeNumber = new DataValidation()
+      .allowWholeNumber(DataValidationRule.between(1, 5))
+      .setInputTitle("Enter a star rating!")
+      .setInputMessage("Enter rating 1-5.\nWhole numbers only.")
+      .setErrorTitle("Value outside allowed range")
+      .setErrorMessage("The input value must be an integer in the range 1-5.");
+    worksheet.addDataValidation(0, 0, 9, 0, wholeNumber);
+    const decimal = new DataValidation()
+      .allowDecimalNumber(DataValidationRule.greaterThan(0.5))
+      .setErrorStyle(DataValidationErrorStyle.Warning)
+      .setMultiRange("B1:B10 D1:D10");
+    worksheet.addDataValidation(0, 1, 9, 1, decimal);
+    const listStrings = new DataValidation()
+      .allowListStrings(["Open", "Overdue", "Done"])
+      .ignoreBlank(false);
+    worksheet.addDataValidation(0, 2, 9, 2, listStrings);
+    const listFormula = new DataValidation()
+      .allowListFormula(new Formula("=$H$1:$H$3"))
+      .showDropdown(false);
+    worksheet.addDataValidation(0, 4, 9, 4, listFormula);
+    const date = new DataValidation().allowDate(
+      DataValidationRule.between(ExcelDateTime.fromYMD(2025, 1, 1), new Date(Date.UTC(2025, 11, 31))),
+    );
+    worksheet.addDataValidation(0, 5, 9, 5, date);
+    const time = new DataValidation().allowTime(DataValidationRule.lessThan(ExcelDateTime.fromHMS(18, 30, 0)));
+    worksheet.addDataValidation(0, 6, 9, 6, time);
+    const textLength = new DataValidation()
+      .allowTextLength(DataValidationRule.lessThanOrEqualTo(8))
+      .showInputMessage(false)
+      .showErrorMessage(false);
+    worksheet.addDataValidation(0, 8, 9, 8, textLength);
+    const wholeNumberFormula = new DataValidation().allowWholeNumberFormula(
+      DataValidationRule.notBetween(new Formula("=$K$1"), new Formula("=$K$2")),
+    );
+    worksheet.addDataValidation(0, 9, 9, 9, wholeNumberFormula);
+    const custom = new DataValidation().allowCustom(new Formula("=ISTEXT(L1)"));
+    worksheet.addDataValidation(0, 11, 9, 11, custom);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/data_validation.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+
+  test("data validation list longer than 255 characters", () => {
+    // Arrange
+    const list = Array.from({ length: 30 }, (_, i) => `Option ${i}`);
+
+    // Act & Assert
+    expect(() => {
+      new DataValidation().allowListStrings(list);
+    }).toThrow("XlsxError(DataValidationError");
+  });
+
+  test("data validation input title longer than 32 characters", () => {
+    // Act & Assert
+    expect(() => {
+      new DataValidation().setInputTitle("This title is much longer than Excel allows");
+    }).toThrow("XlsxError(DataValidationError");
+  });
+
+  test("data validation whole number rule with a non-integer", () => {
+    // Act & Assert
+    expect(() => {
+      new DataValidation().allowWholeNumber(DataValidationRule.equalTo(1.5));
+    }).toThrow("TypeError");
+  });
+
+  test("add data validation with an invalid range", () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+
+    // Act & Assert
+    expect(() => {
+      worksheet.addDataValidation(9, 0, 0, 0, new DataValidation());
+    }).toThrow("XlsxError(RowColumnOrderError)");
+  });
+});
@@ -294,3 +294,85 @@ describe("xlsx-wasm test", () => {
     expect(actual).matchXlsx(expected);
   });
 });
+
+describe("xlsx-wasm test", () => {
+  test("row, column and cell format", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    const header = new Format().setBold().setBackgroundColor(Color.yellow());
+    const money = new Format().setNumFormat("$#,##0.00");
+    const italic = new Format().setItalic();
+    const red = new Format().setFontColor(Color.red());
+
+    // Act
+    worksheet.setRowFormat(0, header);
+    worksheet.setColumnFormat(1, money);
+    worksheet.setColumnRangeFormat(3, 4, italic);
+    worksheet.setColumnRangeWidthPixels(3, 4, 120);
+    worksheet.writeString(0, 0, "Item");
+    worksheet.writeString(0, 1, "Price");
+    worksheet.writeString(1, 0, "Apple");
+    worksheet.writeNumber(1, 1, 1.5);
+    worksheet.writeNumberWithFormat(2, 1, 2.25, red);
+    worksheet.writeString(1, 3, "note");
+    worksheet.writeNumber(2, 2, 3);
+    worksheet.setCellFormat(2, 2, red);
+    worksheet.setCellFormat(3, 0, red);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/format_row_column.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+
+  test("column range format with an invalid range", () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+
+    // Act & Assert
+    expect(() => {
+      worksheet.setColumnRangeFormat(4, 3, new Format());
+    }).toThrow("XlsxError(RowColumnOrderError)");
+  });
+});
+
+describe("xlsx-wasm test", () => {
+  test("hide rows and columns", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet1 = workbook.addWorksheet();
+    const worksheet2 = workbook.addWorksheet();
+    const worksheet3 = workbook.addWorksheet();
+    worksheet1.writeRow(0, 0, ["A", "B", "C", "D", "E"]);
+    worksheet1.writeRow(1, 0, [1, 2, 3, 4, 5]);
+    worksheet1.writeRow(2, 0, [6, 7, 8, 9, 10]);
+
+    // Act
+    worksheet1.setColumnHidden(1);
+    worksheet1.setColumnRangeHidden(3, 4);
+    worksheet1.setRowHidden(1);
+    worksheet1.setRowHidden(2);
+    worksheet1.setRowUnhidden(2);
+    worksheet1.hideUnusedRows(true);
+    worksheet2.setDefaultRowHeight(24);
+    worksheet3.setDefaultRowHeightPixels(40);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/format_hidden.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+
+  test("hide column range with an invalid range", () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+
+    // Act & Assert
+    expect(() => {
+      worksheet.setColumnRangeHidden(4, 3);
+    }).toThrow("XlsxError(RowColumnOrderError)");
+  });
+});
@@ -0,0 +1,52 @@
+import { Formula, IgnoreError, Workbook } from "wasm-xlsxwriter/web";
+import { describe, test, beforeAll, expect } from "vitest";
+import { initWasModule, readXlsx, readXlsxFile } from "./common";
+
+beforeAll(async () => {
+  await initWasModule();
+});
+
+describe("xlsx-wasm test", () => {
+  test("ignore errors in cells and ranges", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    for (let row = 0; row < 10; row++) {
+      worksheet.writeString(row, 0, `${1000 + row}`);
+    }
+    worksheet.writeFormula(0, 2, new Formula("=1/0"));
+    worksheet.writeString(0, 3, "123");
+
+    // Act
+    worksheet.ignoreErrorRange(0, 0, 9, 0, IgnoreError.NumberStoredAsText);
+    worksheet.ignoreError(0, 2, IgnoreError.FormulaError);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/ignore_error.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+
+  test("ignore error twice in the same cell", () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    worksheet.ignoreError(0, 0, IgnoreError.NumberStoredAsText);
+
+    // Act & Assert
+    expect(() => {
+      worksheet.ignoreError(0, 0, IgnoreError.FormulaError);
+    }).toThrow("XlsxError(ParameterError");
+  });
+
+  test("ignore error range with an invalid range", () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+
+    // Act & Assert
+    expect(() => {
+      worksheet.ignoreErrorRange(9, 0, 0, 0, IgnoreError.NumberStoredAsText);
+    }).toThrow("XlsxError(RowColumnOrderError)");
+  });
+});
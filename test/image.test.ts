@@ -96,3 +96,22 @@ describe("xlsx-wasm test", () => {
     expect(actual).matchXlsx(expected);
   });
 });
+
+describe("xlsx-wasm test", () => {
+  test("insert background image", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const imageBuf = loadFile("./fixtures/rust.png");
+    const image = new Image(imageBuf);
+
+    // Act
+    const worksheet = workbook.addWorksheet();
+    worksheet.writeString(0, 0, "Branded template");
+    worksheet.insertBackgroundImage(image);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/background_image.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+});
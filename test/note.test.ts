@@ -43,4 +43,26 @@ describe("xlsx-wasm test", () => {
     const expected = await readXlsxFile("./expected/insert_note.xlsx");
     expect(actual).matchXlsx(expected);
   });
+
+  test("set default note author and show all notes", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+
+    // Act
+    worksheet.setDefaultNoteAuthor("Reviewer");
+    worksheet.showAllNotes(true);
+    worksheet.insertNote(1, 0, new Note("Check this value"));
+    worksheet.insertNote(3, 3, new Note("Looks good"));
+    worksheet.insertNote(
+      6,
+      0,
+      new Note("Written by someone else").setAuthor("Yuya Ryuzaki")
+    );
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/note_defaults.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
 });
@@ -0,0 +1,55 @@
+import { Workbook } from "wasm-xlsxwriter/web";
+import { describe, test, beforeAll, expect } from "vitest";
+import { initWasModule, readXlsx, readXlsxFile } from "./common";
+
+beforeAll(async () => {
+  await initWasModule();
+});
+
+describe("xlsx-wasm test", () => {
+  test("group rows and columns", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet1 = workbook.addWorksheet();
+    const worksheet2 = workbook.addWorksheet();
+
+    // Act
+    worksheet1.groupRows(1, 10);
+    worksheet1.groupRows(1, 4);
+    worksheet1.groupRowsCollapsed(6, 9);
+    worksheet1.groupSymbolsAbove(true);
+    worksheet2.groupColumns(1, 10);
+    worksheet2.groupColumnsCollapsed(2, 4);
+    worksheet2.groupSymbolsToLeft(true);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/outline.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+
+  test("group columns deeper than the outline level limit", () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    for (let level = 0; level < 7; level++) {
+      worksheet.groupColumns(1, 10);
+    }
+
+    // Act & Assert
+    expect(() => {
+      worksheet.groupColumns(1, 10);
+    }).toThrow("XlsxError(MaxGroupLevelExceeded)");
+  });
+
+  test("group rows with an invalid range", () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+
+    // Act & Assert
+    expect(() => {
+      worksheet.groupRowsCollapsed(4, 1);
+    }).toThrow("XlsxError(RowColumnOrderError)");
+  });
+});
@@ -74,4 +74,54 @@ describe("xlsx-wasm test", () => {
     const expected = await readXlsxFile("./expected/print_margins.xlsx");
     expect(actual).matchXlsx(expected);
   });
+
+  test("worksheet page breaks and page order", async () => {
+    // Arrange
+    const workbook = new Workbook();
+
+    // Act
+    const worksheet = workbook.addWorksheet();
+    worksheet.write(0, 0, "Hello, World!");
+    worksheet.setPageBreaks([20, 40, 60, 0, 40]);
+    worksheet.setVerticalPageBreaks([10, 5]);
+    worksheet.setPageOrder(false);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/print_page_breaks.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+
+  test("worksheet too many page breaks", () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    const breaks = Array.from({ length: 1024 }, (_, i) => i + 1);
+
+    // Act & Assert
+    expect(() => {
+      worksheet.setPageBreaks(breaks);
+    }).toThrow("XlsxError(ParameterError");
+  });
+
+  test("worksheet view mode and zoom", async () => {
+    // Arrange
+    const workbook = new Workbook();
+
+    // Act
+    const worksheet1 = workbook.addWorksheet();
+    worksheet1.write(0, 0, "Page layout");
+    worksheet1.setViewPageLayout().setZoom(75);
+    const worksheet2 = workbook.addWorksheet();
+    worksheet2.write(0, 0, "Page break preview");
+    worksheet2.setViewPageBreakPreview().setZoom(200);
+    const worksheet3 = workbook.addWorksheet();
+    worksheet3.write(0, 0, "Normal");
+    worksheet3.setViewPageLayout().setViewNormal().setZoomToFit(false);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/print_view_zoom.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
 });
@@ -0,0 +1,45 @@
+import { ProtectionOptions, Workbook } from "wasm-xlsxwriter/web";
+import { describe, test, beforeAll, expect } from "vitest";
+import { initWasModule, readXlsx, readXlsxFile } from "./common";
+
+beforeAll(async () => {
+  await initWasModule();
+});
+
+describe("xlsx-wasm test", () => {
+  test("protect worksheets with a password and options", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet1 = workbook.addWorksheet();
+    const worksheet2 = workbook.addWorksheet();
+    worksheet1.writeString(0, 0, "Password protected");
+
+    // Act
+    worksheet1.protectWithPassword("abc123");
+    const options = new ProtectionOptions();
+    options.insertRows = true;
+    options.formatColumns = true;
+    options.sort = true;
+    options.useAutofilter = true;
+    options.selectLockedCells = false;
+    worksheet2.protectWithOptions(options);
+    worksheet2.unprotectRange(0, 0, 9, 0);
+    worksheet2.unprotectRangeWithOptions(0, 2, 4, 3, "Inputs", "secret");
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/protection.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+
+  test("unprotect range with an invalid range", () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+
+    // Act & Assert
+    expect(() => {
+      worksheet.unprotectRange(9, 0, 0, 0);
+    }).toThrow("XlsxError(RowColumnOrderError)");
+  });
+});
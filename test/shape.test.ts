@@ -0,0 +1,99 @@
+import {
+  Color,
+  Formula,
+  ObjectMovement,
+  Shape,
+  ShapeFont,
+  ShapeFormat,
+  ShapeGradientFill,
+  ShapeGradientFillType,
+  ShapeGradientStop,
+  ShapeLine,
+  ShapeLineDashType,
+  ShapePatternFill,
+  ShapePatternFillType,
+  ShapeSolidFill,
+  ShapeText,
+  ShapeTextDirection,
+  ShapeTextHorizontalAlignment,
+  ShapeTextVerticalAlignment,
+  Url,
+  Workbook,
+} from "wasm-xlsxwriter/web";
+import { describe, test, beforeAll, expect } from "vitest";
+import { initWasModule, readXlsx, readXlsxFile } from "./common";
+
+beforeAll(async () => {
+  await initWasModule();
+});
+
+describe("xlsx-wasm test", () => {
+  test("insert shapes with formatting", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    worksheet.writeString(0, 0, "Linked text");
+
+    // Act
+    const textbox = Shape.textbox()
+      .setText("Quarterly summary")
+      .setWidth(240)
+      .setHeight(100)
+      .setAltText("Summary box")
+      .setFormat(
+        new ShapeFormat()
+          .setSolidFill(new ShapeSolidFill().setColor(Color.rgb(0x8ed154)).setTransparency(50))
+          .setLine(
+            new ShapeLine()
+              .setColor(Color.red())
+              .setWidth(1.5)
+              .setDashType(ShapeLineDashType.DashDot),
+          ),
+      )
+      .setFont(
+        new ShapeFont().setBold().setItalic().setName("Arial").setColor(Color.blue()).setSize(14),
+      )
+      .setTextOptions(
+        new ShapeText()
+          .setHorizontalAlignment(ShapeTextHorizontalAlignment.Center)
+          .setVerticalAlignment(ShapeTextVerticalAlignment.Middle),
+      );
+    worksheet.insertShape(1, 1, textbox);
+
+    const pattern = Shape.textbox()
+      .setTextLink(new Formula("=Sheet1!A1"))
+      .setFormat(
+        new ShapeFormat().setPatternFill(
+          new ShapePatternFill()
+            .setPattern(ShapePatternFillType.DiagonalBrick)
+            .setBackgroundColor(Color.yellow())
+            .setForegroundColor(Color.orange()),
+        ),
+      )
+      .setObjectMovement(ObjectMovement.DontMoveOrSizeWithCells);
+    worksheet.insertShapeWithOffset(10, 1, pattern, 10, 5);
+
+    const gradient = Shape.textbox()
+      .setText("古池や")
+      .setFormat(
+        new ShapeFormat()
+          .setGradientFill(
+            new ShapeGradientFill()
+              .setType(ShapeGradientFillType.Radial)
+              .setGradientStops([
+                new ShapeGradientStop(Color.rgb(0xf1dcdb), 0),
+                new ShapeGradientStop(Color.rgb(0x963735), 100),
+              ]),
+          )
+          .setNoLine(),
+      )
+      .setTextOptions(new ShapeText().setDirection(ShapeTextDirection.Rotate90EastAsian))
+      .setUrl(new Url("https://www.rust-lang.org"));
+    worksheet.insertShape(1, 6, gradient);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/shape.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+});
@@ -0,0 +1,92 @@
+import {
+  ChartEmptyCells,
+  ChartRange,
+  Color,
+  Sparkline,
+  SparklineType,
+  Workbook,
+} from "wasm-xlsxwriter/web";
+import { describe, test, beforeAll, expect } from "vitest";
+import { initWasModule, readXlsx, readXlsxFile } from "./common";
+
+beforeAll(async () => {
+  await initWasModule();
+});
+
+describe("xlsx-wasm test", () => {
+  test("add sparklines", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    worksheet.writeRowMatrix(0, 0, [
+      [-2, 2, 3, -1, 0],
+      [30, 20, 33, 20, 15],
+      [1, -1, -1, 1, -1],
+    ]);
+    worksheet.writeRow(3, 0, [1, 2, 3, 4, 5]);
+
+    // Act
+    const column = new Sparkline()
+      .setRange(ChartRange.newFromRange("Sheet1", 0, 0, 0, 4))
+      .setType(SparklineType.Column)
+      .setHighPointColor(Color.green())
+      .setLowPointColor(Color.red())
+      .showAxis(true)
+      .setStyle(12);
+    worksheet.addSparkline(0, 5, column);
+    const line = new Sparkline()
+      .setRange(ChartRange.newFromRange("Sheet1", 1, 0, 1, 4))
+      .setDateRange(ChartRange.newFromRange("Sheet1", 3, 0, 3, 4))
+      .setSparklineColor(Color.blue())
+      .setFirstPointColor(Color.orange())
+      .setLastPointColor(Color.purple())
+      .setMarkersColor(Color.gray())
+      .setLineWeight(1.25)
+      .setCustomMin(0)
+      .setCustomMax(40)
+      .setRightToLeft(true)
+      .showEmptyCellsAs(ChartEmptyCells.Zero)
+      .showHiddenData(true);
+    worksheet.addSparkline(1, 5, line);
+    const group = new Sparkline()
+      .setRange(ChartRange.newFromString("Sheet1!$A$1:$E$3"))
+      .setType(SparklineType.WinLose)
+      .setNegativePointsColor(Color.red())
+      .setGroupMax(true)
+      .setGroupMin(true);
+    worksheet.addSparklineGroup(0, 6, 2, 6, group);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/sparkline.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+
+  test("add sparkline with a 2D data range", () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    const sparkline = new Sparkline().setRange(
+      ChartRange.newFromRange("Sheet1", 0, 0, 2, 4),
+    );
+
+    // Act & Assert
+    expect(() => {
+      worksheet.addSparkline(0, 5, sparkline);
+    }).toThrow("XlsxError(SparklineError");
+  });
+
+  test("add sparkline group with a mismatched data range", () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+    const sparkline = new Sparkline().setRange(
+      ChartRange.newFromRange("Sheet1", 0, 0, 2, 4),
+    );
+
+    // Act & Assert
+    expect(() => {
+      worksheet.addSparklineGroup(0, 6, 3, 6, sparkline);
+    }).toThrow("XlsxError(SparklineError");
+  });
+});
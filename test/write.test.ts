@@ -1,4 +1,4 @@
-import { Workbook, Format, Formula, RichString } from "wasm-xlsxwriter/web";
+import { Color, Workbook, Format, Formula, RichString } from "wasm-xlsxwriter/web";
 import { describe, test, beforeAll, expect } from "vitest";
 import { initWasModule, readXlsx, readXlsxFile } from "./common";
 
@@ -155,3 +155,75 @@ describe("xlsx-wasm test", () => {
     expect(actual).matchXlsx(expected);
   });
 });
+
+describe("xlsx-wasm test", () => {
+  test("selection, top left cell and tabs", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet1 = workbook.addWorksheet();
+    const worksheet2 = workbook.addWorksheet();
+    const worksheet3 = workbook.addWorksheet();
+    const worksheet4 = workbook.addWorksheet();
+
+    // Act
+    worksheet1.setSelection(3, 2, 5, 4).setTopLeftCell(2, 1);
+    worksheet2.setFirstTab(true).setSelected(true);
+    worksheet3.setSelected(true).setSelection(10, 3, 10, 3);
+    worksheet4.setActive(true);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/write_selection.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+});
+
+describe("xlsx-wasm test", () => {
+  test("tab color, right to left and very hidden", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet1 = workbook.addWorksheet();
+    const worksheet2 = workbook.addWorksheet();
+    const worksheet3 = workbook.addWorksheet();
+
+    // Act
+    worksheet1.setTabColor(Color.red());
+    worksheet2.setTabColor(Color.rgb(0x1e90ff)).setRightToLeft(true);
+    worksheet2.writeString(0, 0, "نص عربي");
+    worksheet3.writeString(0, 0, "lookup");
+    worksheet3.setVeryHidden(true);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/write_tab_options.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+});
+
+describe("xlsx-wasm test", () => {
+  test("formula results and non-finite numbers", async () => {
+    // Arrange
+    const workbook = new Workbook();
+    const worksheet = workbook.addWorksheet();
+
+    // Act
+    worksheet.setFormulaResultDefault("");
+    worksheet.writeFormula(0, 0, new Formula("=1+1"));
+    worksheet.setFormulaResult(0, 0, "2");
+    worksheet.writeFormula(1, 0, new Formula('="Rust"&"Excel"'));
+    worksheet.setFormulaResult(1, 0, "RustExcel");
+    worksheet.writeFormula(2, 0, new Formula("=SUM(A1:A1)"));
+    worksheet
+      .setNanValue("Not a number")
+      .setInfinityValue("Infinity")
+      .setNegInfinityValue("-Infinity");
+    worksheet.writeNumber(0, 1, NaN);
+    worksheet.writeNumber(1, 1, Infinity);
+    worksheet.writeNumber(2, 1, -Infinity);
+
+    // Assert
+    const actual = await readXlsx(workbook.saveToBufferSync());
+    const expected = await readXlsxFile("./expected/write_formula_result.xlsx");
+    expect(actual).matchXlsx(expected);
+  });
+});